@@ -0,0 +1,174 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use log::{debug, warn};
+
+/// Provides replacement API keys at runtime.
+///
+/// Development keys expire every 24 hours, after which Riot rejects every call with `401` or
+/// `403`. When that happens the client asks its source for a new key and uses it for the
+/// following requests; the call fails with `KeyExpired` only if the key actually changed.
+/// Any `Fn() -> Option<String>` closure can be used as a source, which also makes it a convenient
+/// hook to get notified about the expiration.
+pub trait KeySource: Send + Sync {
+    /// Returns the newest available key, if any.
+    fn fetch_key(&self) -> Option<String>;
+}
+
+impl<F> KeySource for F
+where
+    F: Fn() -> Option<String> + Send + Sync,
+{
+    fn fetch_key(&self) -> Option<String> {
+        self()
+    }
+}
+
+/// Reads the API key from an environment variable.
+pub struct EnvKeySource {
+    var: String,
+}
+
+impl EnvKeySource {
+    pub fn new(var: &str) -> Self {
+        Self {
+            var: var.to_owned(),
+        }
+    }
+}
+
+impl KeySource for EnvKeySource {
+    fn fetch_key(&self) -> Option<String> {
+        env::var(&self.var).ok().and_then(clean_key)
+    }
+}
+
+/// Reads the API key from a file. The file is read again every time the key gets rejected, so
+/// writing a new key to it is enough to rotate the key of a running client.
+pub struct FileKeySource {
+    path: PathBuf,
+}
+
+impl FileKeySource {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl KeySource for FileKeySource {
+    fn fetch_key(&self) -> Option<String> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => clean_key(content),
+            Err(err) => {
+                warn!(
+                    "Could not read API key from {}: {}",
+                    self.path.display(),
+                    err
+                );
+                None
+            }
+        }
+    }
+}
+
+fn clean_key(key: String) -> Option<String> {
+    let key = key.trim();
+
+    if key.is_empty() {
+        None
+    } else {
+        Some(key.to_owned())
+    }
+}
+
+/// API key shared between the client and its in-flight requests.
+#[derive(Clone)]
+pub struct ApiKey {
+    key: Arc<RwLock<String>>,
+    source: Option<Arc<dyn KeySource>>,
+}
+
+impl ApiKey {
+    pub fn new(key: String) -> Self {
+        Self {
+            key: Arc::new(RwLock::new(key)),
+            source: None,
+        }
+    }
+
+    pub fn with_source<S: KeySource + 'static>(mut self, source: S) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
+    /// Key used by new requests.
+    pub fn current(&self) -> String {
+        self.key.read().unwrap().clone()
+    }
+
+    /// Replaces the key used by new requests.
+    pub fn replace(&self, key: String) {
+        *self.key.write().unwrap() = key;
+    }
+
+    /// Asks the key source for a replacement. Returns `true` when a different key was installed.
+    pub fn reload(&self) -> bool {
+        let source = match &self.source {
+            Some(source) => source,
+            None => return false,
+        };
+
+        match source.fetch_key() {
+            Some(key) => {
+                let mut current = self.key.write().unwrap();
+                if *current == key {
+                    debug!("Key source returned the same API key");
+                    return false;
+                }
+
+                *current = key;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reloads_key_from_source() {
+        let key =
+            ApiKey::new(String::from("RGAPI-old")).with_source(|| Some(String::from("RGAPI-new")));
+
+        assert!(key.reload());
+        assert_eq!(key.current(), "RGAPI-new");
+        assert!(!key.reload());
+    }
+
+    #[test]
+    fn keeps_key_without_source() {
+        let key = ApiKey::new(String::from("RGAPI-old"));
+
+        assert!(!key.reload());
+        assert_eq!(key.current(), "RGAPI-old");
+    }
+
+    #[test]
+    fn reads_key_from_file() {
+        let path = env::temp_dir().join(format!("lol-api-key-source-test-{}", std::process::id()));
+        fs::write(&path, "RGAPI-from-file\n").unwrap();
+
+        let key = ApiKey::new(String::from("RGAPI-old")).with_source(FileKeySource::new(&path));
+        assert!(key.reload());
+        assert_eq!(key.current(), "RGAPI-from-file");
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,42 @@
+//! HTTP connector selected through cargo features.
+//!
+//! - `native-tls` (default): TLS through the platform library, OpenSSL on Linux.
+//! - `rustls`: TLS through rustls, which allows fully static builds (e.g. musl).
+//! - Neither: plain HTTP only, meant to run against local mock servers.
+//!
+//! When both TLS features are enabled, `native-tls` takes precedence.
+
+use hyper::client::HttpConnector;
+
+/// Number of threads used for DNS resolution.
+const DNS_WORKER_THREADS: usize = 4;
+
+#[cfg(feature = "native-tls")]
+pub type Connector = hyper_tls::HttpsConnector<HttpConnector>;
+
+#[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+pub type Connector = hyper_rustls::HttpsConnector<HttpConnector>;
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+pub type Connector = HttpConnector;
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+pub(crate) const SCHEME: &str = "https";
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+pub(crate) const SCHEME: &str = "http";
+
+#[cfg(feature = "native-tls")]
+pub(crate) fn build_connector() -> Connector {
+    hyper_tls::HttpsConnector::new(DNS_WORKER_THREADS).unwrap()
+}
+
+#[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+pub(crate) fn build_connector() -> Connector {
+    hyper_rustls::HttpsConnector::new(DNS_WORKER_THREADS)
+}
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+pub(crate) fn build_connector() -> Connector {
+    HttpConnector::new(DNS_WORKER_THREADS)
+}
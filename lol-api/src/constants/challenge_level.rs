@@ -0,0 +1,56 @@
+use super::Tier;
+
+riot_enum! {
+    /// Level reached in a challenge. Matches `Tier` without `Emerald`, plus `None` for challenges
+    /// without progress.
+    pub enum ChallengeLevel: ChallengeLevelError::InvalidChallengeLevel("challenge level") {
+        None = "NONE",
+        Iron = "IRON",
+        Bronze = "BRONZE",
+        Silver = "SILVER",
+        Gold = "GOLD",
+        Platinum = "PLATINUM",
+        Diamond = "DIAMOND",
+        Master = "MASTER",
+        GrandMaster = "GRANDMASTER",
+        Challenger = "CHALLENGER",
+        ..Unknown
+    }
+}
+
+impl ChallengeLevel {
+    /// The matching ranked tier, if any.
+    pub fn tier(&self) -> Option<Tier> {
+        match *self {
+            ChallengeLevel::None => None,
+            ChallengeLevel::Iron => Some(Tier::Iron),
+            ChallengeLevel::Bronze => Some(Tier::Bronze),
+            ChallengeLevel::Silver => Some(Tier::Silver),
+            ChallengeLevel::Gold => Some(Tier::Gold),
+            ChallengeLevel::Platinum => Some(Tier::Platinum),
+            ChallengeLevel::Diamond => Some(Tier::Diamond),
+            ChallengeLevel::Master => Some(Tier::Master),
+            ChallengeLevel::GrandMaster => Some(Tier::GrandMaster),
+            ChallengeLevel::Challenger => Some(Tier::Challenger),
+            ChallengeLevel::Unknown(ref value) => Some(Tier::Unknown(value.clone())),
+        }
+    }
+}
+
+impl From<Tier> for ChallengeLevel {
+    fn from(tier: Tier) -> Self {
+        match tier {
+            Tier::Iron => ChallengeLevel::Iron,
+            Tier::Bronze => ChallengeLevel::Bronze,
+            Tier::Silver => ChallengeLevel::Silver,
+            Tier::Gold => ChallengeLevel::Gold,
+            Tier::Platinum => ChallengeLevel::Platinum,
+            Tier::Emerald => ChallengeLevel::Unknown(String::from("EMERALD")),
+            Tier::Diamond => ChallengeLevel::Diamond,
+            Tier::Master => ChallengeLevel::Master,
+            Tier::GrandMaster => ChallengeLevel::GrandMaster,
+            Tier::Challenger => ChallengeLevel::Challenger,
+            Tier::Unknown(value) => ChallengeLevel::Unknown(value),
+        }
+    }
+}
@@ -0,0 +1,477 @@
+use serde_derive::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Champion, identified by its numeric id. Champions released after this crate are still
+/// represented, they just have no key nor name.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Champion(pub i16);
+
+impl Champion {
+    /// Used by the API for skipped bans.
+    pub const NONE: Champion = Champion(-1);
+
+    pub const ANNIE: Champion = Champion(1);
+    pub const OLAF: Champion = Champion(2);
+    pub const GALIO: Champion = Champion(3);
+    pub const TWISTED_FATE: Champion = Champion(4);
+    pub const XIN_ZHAO: Champion = Champion(5);
+    pub const URGOT: Champion = Champion(6);
+    pub const LEBLANC: Champion = Champion(7);
+    pub const VLADIMIR: Champion = Champion(8);
+    pub const FIDDLESTICKS: Champion = Champion(9);
+    pub const KAYLE: Champion = Champion(10);
+    pub const MASTER_YI: Champion = Champion(11);
+    pub const ALISTAR: Champion = Champion(12);
+    pub const RYZE: Champion = Champion(13);
+    pub const SION: Champion = Champion(14);
+    pub const SIVIR: Champion = Champion(15);
+    pub const SORAKA: Champion = Champion(16);
+    pub const TEEMO: Champion = Champion(17);
+    pub const TRISTANA: Champion = Champion(18);
+    pub const WARWICK: Champion = Champion(19);
+    pub const NUNU: Champion = Champion(20);
+    pub const MISS_FORTUNE: Champion = Champion(21);
+    pub const ASHE: Champion = Champion(22);
+    pub const TRYNDAMERE: Champion = Champion(23);
+    pub const JAX: Champion = Champion(24);
+    pub const MORGANA: Champion = Champion(25);
+    pub const ZILEAN: Champion = Champion(26);
+    pub const SINGED: Champion = Champion(27);
+    pub const EVELYNN: Champion = Champion(28);
+    pub const TWITCH: Champion = Champion(29);
+    pub const KARTHUS: Champion = Champion(30);
+    pub const CHO_GATH: Champion = Champion(31);
+    pub const AMUMU: Champion = Champion(32);
+    pub const RAMMUS: Champion = Champion(33);
+    pub const ANIVIA: Champion = Champion(34);
+    pub const SHACO: Champion = Champion(35);
+    pub const DR_MUNDO: Champion = Champion(36);
+    pub const SONA: Champion = Champion(37);
+    pub const KASSADIN: Champion = Champion(38);
+    pub const IRELIA: Champion = Champion(39);
+    pub const JANNA: Champion = Champion(40);
+    pub const GANGPLANK: Champion = Champion(41);
+    pub const CORKI: Champion = Champion(42);
+    pub const KARMA: Champion = Champion(43);
+    pub const TARIC: Champion = Champion(44);
+    pub const VEIGAR: Champion = Champion(45);
+    pub const TRUNDLE: Champion = Champion(48);
+    pub const SWAIN: Champion = Champion(50);
+    pub const CAITLYN: Champion = Champion(51);
+    pub const BLITZCRANK: Champion = Champion(53);
+    pub const MALPHITE: Champion = Champion(54);
+    pub const KATARINA: Champion = Champion(55);
+    pub const NOCTURNE: Champion = Champion(56);
+    pub const MAOKAI: Champion = Champion(57);
+    pub const RENEKTON: Champion = Champion(58);
+    pub const JARVAN_IV: Champion = Champion(59);
+    pub const ELISE: Champion = Champion(60);
+    pub const ORIANNA: Champion = Champion(61);
+    pub const WUKONG: Champion = Champion(62);
+    pub const BRAND: Champion = Champion(63);
+    pub const LEE_SIN: Champion = Champion(64);
+    pub const VAYNE: Champion = Champion(67);
+    pub const RUMBLE: Champion = Champion(68);
+    pub const CASSIOPEIA: Champion = Champion(69);
+    pub const SKARNER: Champion = Champion(72);
+    pub const HEIMERDINGER: Champion = Champion(74);
+    pub const NASUS: Champion = Champion(75);
+    pub const NIDALEE: Champion = Champion(76);
+    pub const UDYR: Champion = Champion(77);
+    pub const POPPY: Champion = Champion(78);
+    pub const GRAGAS: Champion = Champion(79);
+    pub const PANTHEON: Champion = Champion(80);
+    pub const EZREAL: Champion = Champion(81);
+    pub const MORDEKAISER: Champion = Champion(82);
+    pub const YORICK: Champion = Champion(83);
+    pub const AKALI: Champion = Champion(84);
+    pub const KENNEN: Champion = Champion(85);
+    pub const GAREN: Champion = Champion(86);
+    pub const LEONA: Champion = Champion(89);
+    pub const MALZAHAR: Champion = Champion(90);
+    pub const TALON: Champion = Champion(91);
+    pub const RIVEN: Champion = Champion(92);
+    pub const KOG_MAW: Champion = Champion(96);
+    pub const SHEN: Champion = Champion(98);
+    pub const LUX: Champion = Champion(99);
+    pub const XERATH: Champion = Champion(101);
+    pub const SHYVANA: Champion = Champion(102);
+    pub const AHRI: Champion = Champion(103);
+    pub const GRAVES: Champion = Champion(104);
+    pub const FIZZ: Champion = Champion(105);
+    pub const VOLIBEAR: Champion = Champion(106);
+    pub const RENGAR: Champion = Champion(107);
+    pub const VARUS: Champion = Champion(110);
+    pub const NAUTILUS: Champion = Champion(111);
+    pub const VIKTOR: Champion = Champion(112);
+    pub const SEJUANI: Champion = Champion(113);
+    pub const FIORA: Champion = Champion(114);
+    pub const ZIGGS: Champion = Champion(115);
+    pub const LULU: Champion = Champion(117);
+    pub const DRAVEN: Champion = Champion(119);
+    pub const HECARIM: Champion = Champion(120);
+    pub const KHA_ZIX: Champion = Champion(121);
+    pub const DARIUS: Champion = Champion(122);
+    pub const JAYCE: Champion = Champion(126);
+    pub const LISSANDRA: Champion = Champion(127);
+    pub const DIANA: Champion = Champion(131);
+    pub const QUINN: Champion = Champion(133);
+    pub const SYNDRA: Champion = Champion(134);
+    pub const AURELION_SOL: Champion = Champion(136);
+    pub const KAYN: Champion = Champion(141);
+    pub const ZOE: Champion = Champion(142);
+    pub const ZYRA: Champion = Champion(143);
+    pub const KAI_SA: Champion = Champion(145);
+    pub const SERAPHINE: Champion = Champion(147);
+    pub const GNAR: Champion = Champion(150);
+    pub const ZAC: Champion = Champion(154);
+    pub const YASUO: Champion = Champion(157);
+    pub const VEL_KOZ: Champion = Champion(161);
+    pub const TALIYAH: Champion = Champion(163);
+    pub const CAMILLE: Champion = Champion(164);
+    pub const AKSHAN: Champion = Champion(166);
+    pub const BEL_VETH: Champion = Champion(200);
+    pub const BRAUM: Champion = Champion(201);
+    pub const JHIN: Champion = Champion(202);
+    pub const KINDRED: Champion = Champion(203);
+    pub const ZERI: Champion = Champion(221);
+    pub const JINX: Champion = Champion(222);
+    pub const TAHM_KENCH: Champion = Champion(223);
+    pub const BRIAR: Champion = Champion(233);
+    pub const VIEGO: Champion = Champion(234);
+    pub const SENNA: Champion = Champion(235);
+    pub const LUCIAN: Champion = Champion(236);
+    pub const ZED: Champion = Champion(238);
+    pub const KLED: Champion = Champion(240);
+    pub const EKKO: Champion = Champion(245);
+    pub const QIYANA: Champion = Champion(246);
+    pub const VI: Champion = Champion(254);
+    pub const AATROX: Champion = Champion(266);
+    pub const NAMI: Champion = Champion(267);
+    pub const AZIR: Champion = Champion(268);
+    pub const YUUMI: Champion = Champion(350);
+    pub const SAMIRA: Champion = Champion(360);
+    pub const THRESH: Champion = Champion(412);
+    pub const ILLAOI: Champion = Champion(420);
+    pub const REK_SAI: Champion = Champion(421);
+    pub const IVERN: Champion = Champion(427);
+    pub const KALISTA: Champion = Champion(429);
+    pub const BARD: Champion = Champion(432);
+    pub const RAKAN: Champion = Champion(497);
+    pub const XAYAH: Champion = Champion(498);
+    pub const ORNN: Champion = Champion(516);
+    pub const SYLAS: Champion = Champion(517);
+    pub const NEEKO: Champion = Champion(518);
+    pub const APHELIOS: Champion = Champion(523);
+    pub const RELL: Champion = Champion(526);
+    pub const PYKE: Champion = Champion(555);
+    pub const VEX: Champion = Champion(711);
+    pub const YONE: Champion = Champion(777);
+    pub const AMBESSA: Champion = Champion(799);
+    pub const MEL: Champion = Champion(800);
+    pub const SETT: Champion = Champion(875);
+    pub const LILLIA: Champion = Champion(876);
+    pub const GWEN: Champion = Champion(887);
+    pub const RENATA: Champion = Champion(888);
+    pub const AURORA: Champion = Champion(893);
+    pub const NILAH: Champion = Champion(895);
+    pub const K_SANTE: Champion = Champion(897);
+    pub const SMOLDER: Champion = Champion(901);
+    pub const MILIO: Champion = Champion(902);
+    pub const HWEI: Champion = Champion(910);
+    pub const NAAFIRI: Champion = Champion(950);
+
+    pub fn id(&self) -> i16 {
+        self.0
+    }
+
+    /// Key of the champion in Data Dragon, like `MonkeyKing`.
+    pub fn key(&self) -> Option<&'static str> {
+        self.info().map(|(_, key, _)| *key)
+    }
+
+    /// Display name of the champion, like `Wukong`.
+    pub fn name(&self) -> Option<&'static str> {
+        self.info().map(|(_, _, name)| *name)
+    }
+
+    /// Whether the id belongs to a champion known by this crate.
+    pub fn is_known(&self) -> bool {
+        self.info().is_some()
+    }
+
+    fn info(&self) -> Option<&'static (Champion, &'static str, &'static str)> {
+        CHAMPIONS
+            .binary_search_by_key(self, |(champion, _, _)| *champion)
+            .ok()
+            .map(|index| &CHAMPIONS[index])
+    }
+}
+
+impl fmt::Display for Champion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl fmt::Debug for Champion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.key() {
+            Some(key) => write!(f, "{}({})", key, self.0),
+            None => write!(f, "Champion({})", self.0),
+        }
+    }
+}
+
+impl FromStr for Champion {
+    type Err = ChampionError;
+
+    /// Parses a numeric id, or a key or name ignoring case and punctuation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(id) = s.parse::<i16>() {
+            return Ok(Champion(id));
+        }
+
+        let wanted = normalize(s);
+        CHAMPIONS
+            .iter()
+            .find(|(_, key, name)| normalize(key) == wanted || normalize(name) == wanted)
+            .map(|(champion, _, _)| *champion)
+            .ok_or_else(|| ChampionError::InvalidChampion {
+                value: s.to_owned(),
+            })
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[derive(Debug, Fail)]
+pub enum ChampionError {
+    #[fail(display = "invalid champion: {}", value)]
+    InvalidChampion { value: String },
+}
+
+/// Known champions with their key and name, sorted by id.
+static CHAMPIONS: &[(Champion, &str, &str)] = &[
+    (Champion::ANNIE, "Annie", "Annie"),
+    (Champion::OLAF, "Olaf", "Olaf"),
+    (Champion::GALIO, "Galio", "Galio"),
+    (Champion::TWISTED_FATE, "TwistedFate", "Twisted Fate"),
+    (Champion::XIN_ZHAO, "XinZhao", "Xin Zhao"),
+    (Champion::URGOT, "Urgot", "Urgot"),
+    (Champion::LEBLANC, "Leblanc", "LeBlanc"),
+    (Champion::VLADIMIR, "Vladimir", "Vladimir"),
+    (Champion::FIDDLESTICKS, "Fiddlesticks", "Fiddlesticks"),
+    (Champion::KAYLE, "Kayle", "Kayle"),
+    (Champion::MASTER_YI, "MasterYi", "Master Yi"),
+    (Champion::ALISTAR, "Alistar", "Alistar"),
+    (Champion::RYZE, "Ryze", "Ryze"),
+    (Champion::SION, "Sion", "Sion"),
+    (Champion::SIVIR, "Sivir", "Sivir"),
+    (Champion::SORAKA, "Soraka", "Soraka"),
+    (Champion::TEEMO, "Teemo", "Teemo"),
+    (Champion::TRISTANA, "Tristana", "Tristana"),
+    (Champion::WARWICK, "Warwick", "Warwick"),
+    (Champion::NUNU, "Nunu", "Nunu & Willump"),
+    (Champion::MISS_FORTUNE, "MissFortune", "Miss Fortune"),
+    (Champion::ASHE, "Ashe", "Ashe"),
+    (Champion::TRYNDAMERE, "Tryndamere", "Tryndamere"),
+    (Champion::JAX, "Jax", "Jax"),
+    (Champion::MORGANA, "Morgana", "Morgana"),
+    (Champion::ZILEAN, "Zilean", "Zilean"),
+    (Champion::SINGED, "Singed", "Singed"),
+    (Champion::EVELYNN, "Evelynn", "Evelynn"),
+    (Champion::TWITCH, "Twitch", "Twitch"),
+    (Champion::KARTHUS, "Karthus", "Karthus"),
+    (Champion::CHO_GATH, "Chogath", "Cho'Gath"),
+    (Champion::AMUMU, "Amumu", "Amumu"),
+    (Champion::RAMMUS, "Rammus", "Rammus"),
+    (Champion::ANIVIA, "Anivia", "Anivia"),
+    (Champion::SHACO, "Shaco", "Shaco"),
+    (Champion::DR_MUNDO, "DrMundo", "Dr. Mundo"),
+    (Champion::SONA, "Sona", "Sona"),
+    (Champion::KASSADIN, "Kassadin", "Kassadin"),
+    (Champion::IRELIA, "Irelia", "Irelia"),
+    (Champion::JANNA, "Janna", "Janna"),
+    (Champion::GANGPLANK, "Gangplank", "Gangplank"),
+    (Champion::CORKI, "Corki", "Corki"),
+    (Champion::KARMA, "Karma", "Karma"),
+    (Champion::TARIC, "Taric", "Taric"),
+    (Champion::VEIGAR, "Veigar", "Veigar"),
+    (Champion::TRUNDLE, "Trundle", "Trundle"),
+    (Champion::SWAIN, "Swain", "Swain"),
+    (Champion::CAITLYN, "Caitlyn", "Caitlyn"),
+    (Champion::BLITZCRANK, "Blitzcrank", "Blitzcrank"),
+    (Champion::MALPHITE, "Malphite", "Malphite"),
+    (Champion::KATARINA, "Katarina", "Katarina"),
+    (Champion::NOCTURNE, "Nocturne", "Nocturne"),
+    (Champion::MAOKAI, "Maokai", "Maokai"),
+    (Champion::RENEKTON, "Renekton", "Renekton"),
+    (Champion::JARVAN_IV, "JarvanIV", "Jarvan IV"),
+    (Champion::ELISE, "Elise", "Elise"),
+    (Champion::ORIANNA, "Orianna", "Orianna"),
+    (Champion::WUKONG, "MonkeyKing", "Wukong"),
+    (Champion::BRAND, "Brand", "Brand"),
+    (Champion::LEE_SIN, "LeeSin", "Lee Sin"),
+    (Champion::VAYNE, "Vayne", "Vayne"),
+    (Champion::RUMBLE, "Rumble", "Rumble"),
+    (Champion::CASSIOPEIA, "Cassiopeia", "Cassiopeia"),
+    (Champion::SKARNER, "Skarner", "Skarner"),
+    (Champion::HEIMERDINGER, "Heimerdinger", "Heimerdinger"),
+    (Champion::NASUS, "Nasus", "Nasus"),
+    (Champion::NIDALEE, "Nidalee", "Nidalee"),
+    (Champion::UDYR, "Udyr", "Udyr"),
+    (Champion::POPPY, "Poppy", "Poppy"),
+    (Champion::GRAGAS, "Gragas", "Gragas"),
+    (Champion::PANTHEON, "Pantheon", "Pantheon"),
+    (Champion::EZREAL, "Ezreal", "Ezreal"),
+    (Champion::MORDEKAISER, "Mordekaiser", "Mordekaiser"),
+    (Champion::YORICK, "Yorick", "Yorick"),
+    (Champion::AKALI, "Akali", "Akali"),
+    (Champion::KENNEN, "Kennen", "Kennen"),
+    (Champion::GAREN, "Garen", "Garen"),
+    (Champion::LEONA, "Leona", "Leona"),
+    (Champion::MALZAHAR, "Malzahar", "Malzahar"),
+    (Champion::TALON, "Talon", "Talon"),
+    (Champion::RIVEN, "Riven", "Riven"),
+    (Champion::KOG_MAW, "KogMaw", "Kog'Maw"),
+    (Champion::SHEN, "Shen", "Shen"),
+    (Champion::LUX, "Lux", "Lux"),
+    (Champion::XERATH, "Xerath", "Xerath"),
+    (Champion::SHYVANA, "Shyvana", "Shyvana"),
+    (Champion::AHRI, "Ahri", "Ahri"),
+    (Champion::GRAVES, "Graves", "Graves"),
+    (Champion::FIZZ, "Fizz", "Fizz"),
+    (Champion::VOLIBEAR, "Volibear", "Volibear"),
+    (Champion::RENGAR, "Rengar", "Rengar"),
+    (Champion::VARUS, "Varus", "Varus"),
+    (Champion::NAUTILUS, "Nautilus", "Nautilus"),
+    (Champion::VIKTOR, "Viktor", "Viktor"),
+    (Champion::SEJUANI, "Sejuani", "Sejuani"),
+    (Champion::FIORA, "Fiora", "Fiora"),
+    (Champion::ZIGGS, "Ziggs", "Ziggs"),
+    (Champion::LULU, "Lulu", "Lulu"),
+    (Champion::DRAVEN, "Draven", "Draven"),
+    (Champion::HECARIM, "Hecarim", "Hecarim"),
+    (Champion::KHA_ZIX, "Khazix", "Kha'Zix"),
+    (Champion::DARIUS, "Darius", "Darius"),
+    (Champion::JAYCE, "Jayce", "Jayce"),
+    (Champion::LISSANDRA, "Lissandra", "Lissandra"),
+    (Champion::DIANA, "Diana", "Diana"),
+    (Champion::QUINN, "Quinn", "Quinn"),
+    (Champion::SYNDRA, "Syndra", "Syndra"),
+    (Champion::AURELION_SOL, "AurelionSol", "Aurelion Sol"),
+    (Champion::KAYN, "Kayn", "Kayn"),
+    (Champion::ZOE, "Zoe", "Zoe"),
+    (Champion::ZYRA, "Zyra", "Zyra"),
+    (Champion::KAI_SA, "Kaisa", "Kai'Sa"),
+    (Champion::SERAPHINE, "Seraphine", "Seraphine"),
+    (Champion::GNAR, "Gnar", "Gnar"),
+    (Champion::ZAC, "Zac", "Zac"),
+    (Champion::YASUO, "Yasuo", "Yasuo"),
+    (Champion::VEL_KOZ, "Velkoz", "Vel'Koz"),
+    (Champion::TALIYAH, "Taliyah", "Taliyah"),
+    (Champion::CAMILLE, "Camille", "Camille"),
+    (Champion::AKSHAN, "Akshan", "Akshan"),
+    (Champion::BEL_VETH, "Belveth", "Bel'Veth"),
+    (Champion::BRAUM, "Braum", "Braum"),
+    (Champion::JHIN, "Jhin", "Jhin"),
+    (Champion::KINDRED, "Kindred", "Kindred"),
+    (Champion::ZERI, "Zeri", "Zeri"),
+    (Champion::JINX, "Jinx", "Jinx"),
+    (Champion::TAHM_KENCH, "TahmKench", "Tahm Kench"),
+    (Champion::BRIAR, "Briar", "Briar"),
+    (Champion::VIEGO, "Viego", "Viego"),
+    (Champion::SENNA, "Senna", "Senna"),
+    (Champion::LUCIAN, "Lucian", "Lucian"),
+    (Champion::ZED, "Zed", "Zed"),
+    (Champion::KLED, "Kled", "Kled"),
+    (Champion::EKKO, "Ekko", "Ekko"),
+    (Champion::QIYANA, "Qiyana", "Qiyana"),
+    (Champion::VI, "Vi", "Vi"),
+    (Champion::AATROX, "Aatrox", "Aatrox"),
+    (Champion::NAMI, "Nami", "Nami"),
+    (Champion::AZIR, "Azir", "Azir"),
+    (Champion::YUUMI, "Yuumi", "Yuumi"),
+    (Champion::SAMIRA, "Samira", "Samira"),
+    (Champion::THRESH, "Thresh", "Thresh"),
+    (Champion::ILLAOI, "Illaoi", "Illaoi"),
+    (Champion::REK_SAI, "RekSai", "Rek'Sai"),
+    (Champion::IVERN, "Ivern", "Ivern"),
+    (Champion::KALISTA, "Kalista", "Kalista"),
+    (Champion::BARD, "Bard", "Bard"),
+    (Champion::RAKAN, "Rakan", "Rakan"),
+    (Champion::XAYAH, "Xayah", "Xayah"),
+    (Champion::ORNN, "Ornn", "Ornn"),
+    (Champion::SYLAS, "Sylas", "Sylas"),
+    (Champion::NEEKO, "Neeko", "Neeko"),
+    (Champion::APHELIOS, "Aphelios", "Aphelios"),
+    (Champion::RELL, "Rell", "Rell"),
+    (Champion::PYKE, "Pyke", "Pyke"),
+    (Champion::VEX, "Vex", "Vex"),
+    (Champion::YONE, "Yone", "Yone"),
+    (Champion::AMBESSA, "Ambessa", "Ambessa"),
+    (Champion::MEL, "Mel", "Mel"),
+    (Champion::SETT, "Sett", "Sett"),
+    (Champion::LILLIA, "Lillia", "Lillia"),
+    (Champion::GWEN, "Gwen", "Gwen"),
+    (Champion::RENATA, "Renata", "Renata Glasc"),
+    (Champion::AURORA, "Aurora", "Aurora"),
+    (Champion::NILAH, "Nilah", "Nilah"),
+    (Champion::K_SANTE, "KSante", "K'Sante"),
+    (Champion::SMOLDER, "Smolder", "Smolder"),
+    (Champion::MILIO, "Milio", "Milio"),
+    (Champion::HWEI, "Hwei", "Hwei"),
+    (Champion::NAAFIRI, "Naafiri", "Naafiri"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn champions_are_sorted_by_id() {
+        assert!(CHAMPIONS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn maps_ids_to_keys_and_names() {
+        assert_eq!(Champion::WUKONG.key(), Some("MonkeyKing"));
+        assert_eq!(Champion::WUKONG.name(), Some("Wukong"));
+        assert_eq!(Champion::KAI_SA.to_string(), "Kai'Sa");
+        assert_eq!(format!("{:?}", Champion::AHRI), "Ahri(103)");
+    }
+
+    #[test]
+    fn keeps_unknown_champions() {
+        let champion: Champion = serde_json::from_str("4242").unwrap();
+
+        assert!(!champion.is_known());
+        assert_eq!(champion.name(), None);
+        assert_eq!(champion.to_string(), "4242");
+        assert_eq!(serde_json::to_string(&champion).unwrap(), "4242");
+        assert!(!Champion::NONE.is_known());
+    }
+
+    #[test]
+    fn parses_ids_keys_and_names() {
+        assert_eq!("103".parse::<Champion>().unwrap(), Champion::AHRI);
+        assert_eq!("MonkeyKing".parse::<Champion>().unwrap(), Champion::WUKONG);
+        assert_eq!("kog'maw".parse::<Champion>().unwrap(), Champion::KOG_MAW);
+        assert_eq!(
+            "Nunu & Willump".parse::<Champion>().unwrap(),
+            Champion::NUNU
+        );
+        assert!("Not a champion".parse::<Champion>().is_err());
+    }
+}
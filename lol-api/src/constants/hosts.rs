@@ -1,3 +1,13 @@
+/// Kind of host an endpoint is served from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Routing {
+    /// Platform hosts, like `euw1.api.riotgames.com`. See `Platforms`.
+    Platform,
+    /// Regional hosts, like `europe.api.riotgames.com`. See `Regions`.
+    Regional,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Regions {
     Americas,
     Asia,
@@ -5,15 +15,20 @@ pub enum Regions {
 }
 
 impl WithHost for Regions {
-    fn host(&self, hostname: &str) -> String {
+    fn name(&self) -> &'static str {
         match *self {
-            Regions::Americas => format!("americas.{}", hostname),
-            Regions::Asia => format!("asia.{}", hostname),
-            Regions::Europe => format!("europe.{}", hostname),
+            Regions::Americas => "americas",
+            Regions::Asia => "asia",
+            Regions::Europe => "europe",
         }
     }
+
+    fn routing(&self) -> Routing {
+        Routing::Regional
+    }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Platforms {
     Br1,
     Eun1,
@@ -29,23 +44,35 @@ pub enum Platforms {
 }
 
 impl WithHost for Platforms {
-    fn host(&self, hostname: &str) -> String {
+    fn name(&self) -> &'static str {
         match *self {
-            Platforms::Br1 => format!("br1.{}", hostname),
-            Platforms::Eun1 => format!("eun1.{}", hostname),
-            Platforms::Euw1 => format!("euw1.{}", hostname),
-            Platforms::Jp1 => format!("jp1.{}", hostname),
-            Platforms::Kr => format!("kr.{}", hostname),
-            Platforms::La1 => format!("la1.{}", hostname),
-            Platforms::La2 => format!("la2.{}", hostname),
-            Platforms::Na1 => format!("na1.{}", hostname),
-            Platforms::Oc1 => format!("oc1.{}", hostname),
-            Platforms::Tr1 => format!("tr1.{}", hostname),
-            Platforms::Ru => format!("ru.{}", hostname),
+            Platforms::Br1 => "br1",
+            Platforms::Eun1 => "eun1",
+            Platforms::Euw1 => "euw1",
+            Platforms::Jp1 => "jp1",
+            Platforms::Kr => "kr",
+            Platforms::La1 => "la1",
+            Platforms::La2 => "la2",
+            Platforms::Na1 => "na1",
+            Platforms::Oc1 => "oc1",
+            Platforms::Tr1 => "tr1",
+            Platforms::Ru => "ru",
         }
     }
+
+    fn routing(&self) -> Routing {
+        Routing::Platform
+    }
 }
 
 pub trait WithHost {
-    fn host(&self, hostname: &str) -> String;
+    /// Subdomain identifying the platform or region, e.g. `euw1` or `europe`.
+    fn name(&self) -> &'static str;
+
+    /// Kind of routing this host serves.
+    fn routing(&self) -> Routing;
+
+    fn host(&self, hostname: &str) -> String {
+        format!("{}.{}", self.name(), hostname)
+    }
 }
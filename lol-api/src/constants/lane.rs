@@ -0,0 +1,13 @@
+riot_enum! {
+    /// Lane a participant was assigned to by the match analysis. The legacy `MID` and `BOT` values
+    /// are read as `Middle` and `Bottom`.
+    pub enum Lane: LaneError::InvalidLane("lane") {
+        Top = "TOP",
+        Jungle = "JUNGLE",
+        Middle = "MIDDLE" | "MID",
+        Bottom = "BOTTOM" | "BOT",
+        /// Lane could not be determined.
+        None = "NONE",
+        ..Unknown
+    }
+}
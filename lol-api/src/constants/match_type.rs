@@ -0,0 +1,10 @@
+riot_enum! {
+    /// Match type filter for match-v5 match id lists. Only ever sent to Riot, so it has no
+    /// `Unknown` variant.
+    pub enum MatchType: MatchTypeError::InvalidMatchType("match type") {
+        Ranked = "ranked",
+        Normal = "normal",
+        Tourney = "tourney",
+        Tutorial = "tutorial",
+    }
+}
@@ -1,9 +1,122 @@
+mod challenge_level;
+mod champion;
+mod division;
 mod hosts;
+mod lane;
+mod match_type;
+mod position;
 mod queue;
-mod division;
+mod rank;
+mod role;
+mod strict;
 mod tier;
 
+pub use self::challenge_level::*;
+pub use self::champion::*;
+pub use self::division::*;
 pub use self::hosts::*;
+pub use self::lane::*;
+pub use self::match_type::*;
+pub use self::position::*;
 pub use self::queue::*;
-pub use self::division::*;
+pub use self::rank::*;
+pub use self::role::*;
+pub use self::strict::*;
 pub use self::tier::*;
+
+use serde::de;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parses a value sent by Riot, keeping values this crate doesn't know about with `unknown`.
+/// Callers that would rather fail use `Strict`.
+#[doc(hidden)]
+pub fn parse_or_unknown<T, E>(value: &str, unknown: fn(String) -> T) -> Result<T, E>
+where
+    T: FromStr,
+    T::Err: Display,
+    E: de::Error,
+{
+    match value.parse::<T>() {
+        Ok(parsed) => Ok(parsed),
+        Err(_) => Ok(unknown(value.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_derive::Deserialize;
+    use std::fmt::Debug;
+
+    fn assert_round_trips<T>(variants: impl Iterator<Item = T>)
+    where
+        T: FromStr + Serialize + DeserializeOwned + Display + Debug + PartialEq,
+        T::Err: Debug,
+    {
+        for variant in variants {
+            let json = serde_json::to_string(&variant).unwrap();
+
+            assert_eq!(variant.to_string().parse::<T>().unwrap(), variant);
+            assert_eq!(serde_json::from_str::<T>(&json).unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn round_trips_every_variant() {
+        assert_round_trips(ChallengeLevel::variants());
+        assert_round_trips(Division::variants());
+        assert_round_trips(Lane::variants());
+        assert_round_trips(MatchType::variants());
+        assert_round_trips(Position::variants());
+        assert_round_trips(Queue::variants());
+        assert_round_trips(Role::variants());
+        assert_round_trips(Tier::variants());
+        assert_eq!(Tier::VARIANTS.len(), 10);
+    }
+
+    #[test]
+    fn keeps_unknown_values() {
+        let queue: Queue = serde_json::from_str(r#""CHERRY""#).unwrap();
+        let tiers: Vec<Tier> = serde_json::from_str(r#"["GOLD", "ADAMANT"]"#).unwrap();
+
+        assert_eq!(queue, Queue::Unknown("CHERRY".to_owned()));
+        assert_eq!(serde_json::to_string(&queue).unwrap(), r#""CHERRY""#);
+        assert_eq!(tiers[1], Tier::Unknown("ADAMANT".to_owned()));
+        assert!("ADAMANT".parse::<Tier>().is_err());
+    }
+
+    #[test]
+    fn sorts_unknown_values_first() {
+        let adamant = Tier::Unknown("ADAMANT".to_owned());
+        let zero = Division::Unknown("0".to_owned());
+
+        assert!(adamant < Tier::Iron);
+        assert!(adamant < Tier::Unknown("AETHER".to_owned()));
+        assert!(zero < Division::V);
+    }
+
+    #[test]
+    fn rejects_unknown_values_when_strict() {
+        #[derive(Deserialize)]
+        struct Entry {
+            #[serde(deserialize_with = "strict")]
+            tier: Tier,
+        }
+
+        let queues: Vec<Strict<Queue>> =
+            serde_json::from_str(r#"["RANKED_SOLO_5x5", "ranked_flex_sr"]"#).unwrap();
+        assert_eq!(*queues[1], Queue::RankedFlexSr);
+        assert!(serde_json::from_str::<Strict<Queue>>(r#""CHERRY""#).is_err());
+        assert!(serde_json::from_str::<Strict<Division>>(r#""I""#).is_ok());
+
+        assert_eq!(
+            serde_json::from_str::<Entry>(r#"{"tier":"GOLD"}"#)
+                .unwrap()
+                .tier,
+            Tier::Gold
+        );
+        assert!(serde_json::from_str::<Entry>(r#"{"tier":"ADAMANT"}"#).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use super::{Lane, Role};
+
+riot_enum! {
+    /// Position a participant played, derived from the lane and role of the match analysis. Never
+    /// sent by Riot, so it has no `Unknown` variant.
+    pub enum Position: PositionError::InvalidPosition("position") {
+        Top = "TOP",
+        Jungle = "JUNGLE",
+        Mid = "MID",
+        Adc = "ADC",
+        Support = "SUPPORT",
+    }
+}
+
+impl Position {
+    /// Combines a lane and a role. Bottom lane needs the role to tell the carry apart from the
+    /// support, so `None` is returned when it's missing or when the lane is unknown.
+    pub fn from_lane_role(lane: Lane, role: Role) -> Option<Position> {
+        match (lane, role) {
+            (Lane::Top, _) => Some(Position::Top),
+            (Lane::Jungle, _) => Some(Position::Jungle),
+            (Lane::Middle, _) => Some(Position::Mid),
+            (Lane::Bottom, Role::DuoCarry) => Some(Position::Adc),
+            (Lane::Bottom, Role::DuoSupport) => Some(Position::Support),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises_legacy_lanes() {
+        assert_eq!("MID".parse::<Lane>().unwrap(), Lane::Middle);
+        assert_eq!("BOT".parse::<Lane>().unwrap(), Lane::Bottom);
+        assert_eq!(Lane::Middle.value(), "MIDDLE");
+        assert!("RIVER".parse::<Lane>().is_err());
+    }
+
+    #[test]
+    fn derives_positions() {
+        let positions = vec![
+            (Lane::Top, Role::Solo, Some(Position::Top)),
+            (Lane::Jungle, Role::None, Some(Position::Jungle)),
+            (Lane::Middle, Role::Solo, Some(Position::Mid)),
+            (Lane::Bottom, Role::DuoCarry, Some(Position::Adc)),
+            (Lane::Bottom, Role::DuoSupport, Some(Position::Support)),
+            (Lane::Bottom, Role::Duo, None),
+            (Lane::None, Role::DuoSupport, None),
+        ];
+
+        for (lane, role, position) in positions {
+            assert_eq!(Position::from_lane_role(lane, role), position);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt;
+
+use super::{Division, Tier};
+
+/// Tiers below apex, which are split into divisions, from lowest to highest.
+const DIVIDED_TIERS: [Tier; 7] = [
+    Tier::Iron,
+    Tier::Bronze,
+    Tier::Silver,
+    Tier::Gold,
+    Tier::Platinum,
+    Tier::Emerald,
+    Tier::Diamond,
+];
+
+/// Divisions of a tier, from lowest to highest.
+const DIVISIONS: [Division; 4] = [Division::IV, Division::III, Division::II, Division::I];
+
+/// League points between two divisions.
+const DIVISION_LP: u32 = 100;
+
+/// Ladder score of `Master 0 LP`.
+const APEX_SCORE: u32 = DIVIDED_TIERS.len() as u32 * DIVISIONS.len() as u32 * DIVISION_LP;
+
+/// Position of a player on the ranked ladder. Apex tiers have no division.
+///
+/// Ranks are ordered by tier, then division, then league points. Apex tiers are compared by tier
+/// first, so `GrandMaster 0 LP` is above `Master 500 LP` even though its `score` is lower.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Rank {
+    tier: Tier,
+    division: Option<Division>,
+    lp: u32,
+}
+
+impl Rank {
+    /// Builds a rank, dropping the division of apex tiers since Riot always sends `I` for them.
+    /// Division `V`, from the seasons before 2019, is read as `IV`. `None` if the tier or the
+    /// division is unknown, since it can't be placed on the ladder.
+    pub fn new(tier: Tier, division: Division, lp: u32) -> Option<Self> {
+        let division = match (&tier, division) {
+            (Tier::Unknown(_), _) => return None,
+            (tier, _) if tier.is_apex() => None,
+            (_, Division::Unknown(_)) => return None,
+            (_, Division::V) => Some(Division::IV),
+            (_, division) => Some(division),
+        };
+
+        Some(Rank { tier, division, lp })
+    }
+
+    pub fn tier(&self) -> Tier {
+        self.tier.clone()
+    }
+
+    pub fn division(&self) -> Option<Division> {
+        self.division.clone()
+    }
+
+    pub fn lp(&self) -> u32 {
+        self.lp
+    }
+
+    /// Single number for the rank, where each division is worth 100 LP starting at `Iron IV 0 LP`.
+    /// Apex tiers share the same ladder, like they do in game, so unlike `Ord` a `Master` rank can
+    /// score above a `GrandMaster` one.
+    pub fn score(&self) -> u32 {
+        let division = match self.division {
+            Some(ref division) => DIVISIONS.iter().position(|d| d == division).unwrap_or(0),
+            None => return APEX_SCORE + self.lp,
+        };
+        let tier = DIVIDED_TIERS
+            .iter()
+            .position(|t| *t == self.tier)
+            .unwrap_or(0);
+
+        (tier * DIVISIONS.len() + division) as u32 * DIVISION_LP + self.lp
+    }
+
+    /// Rank for a ladder score. Apex scores are returned as `Master`, since the apex tier
+    /// depends on the ladder cutoffs and not only on league points.
+    pub fn from_score(score: u32) -> Self {
+        if score >= APEX_SCORE {
+            return Rank {
+                tier: Tier::Master,
+                division: None,
+                lp: score - APEX_SCORE,
+            };
+        }
+
+        let index = (score / DIVISION_LP) as usize;
+        Rank {
+            tier: DIVIDED_TIERS[index / DIVISIONS.len()].clone(),
+            division: Some(DIVISIONS[index % DIVISIONS.len()].clone()),
+            lp: score % DIVISION_LP,
+        }
+    }
+
+    /// Rank of the average ladder score, like the average rank of a team. `None` if there are
+    /// no ranks.
+    pub fn average<'a, I>(ranks: I) -> Option<Rank>
+    where
+        I: IntoIterator<Item = &'a Rank>,
+    {
+        let (total, count) = ranks
+            .into_iter()
+            .fold((0u64, 0u64), |(total, count), rank| {
+                (total + u64::from(rank.score()), count + 1)
+            });
+
+        if count == 0 {
+            return None;
+        }
+
+        let average = (total as f64 / count as f64).round() as u32;
+        Some(Rank::from_score(average))
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.division {
+            Some(ref division) => write!(f, "{} {} {} LP", self.tier, division, self.lp),
+            None => write!(f, "{} {} LP", self.tier, self.lp),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rank(tier: Tier, division: Division, lp: u32) -> Rank {
+        Rank::new(tier, division, lp).unwrap()
+    }
+
+    #[test]
+    fn orders_ranks() {
+        let mut ranks = [
+            rank(Tier::Master, Division::I, 12),
+            rank(Tier::Gold, Division::I, 0),
+            rank(Tier::Gold, Division::IV, 99),
+            rank(Tier::Challenger, Division::I, 0),
+            rank(Tier::Gold, Division::I, 50),
+            rank(Tier::GrandMaster, Division::I, 0),
+            rank(Tier::Master, Division::I, 500),
+            rank(Tier::Gold, Division::V, 10),
+        ];
+        ranks.sort();
+
+        let ranks: Vec<String> = ranks.iter().map(Rank::to_string).collect();
+        assert_eq!(
+            ranks,
+            vec![
+                "GOLD IV 10 LP",
+                "GOLD IV 99 LP",
+                "GOLD I 0 LP",
+                "GOLD I 50 LP",
+                "MASTER 12 LP",
+                "MASTER 500 LP",
+                "GRANDMASTER 0 LP",
+                "CHALLENGER 0 LP",
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_scores() {
+        assert_eq!(rank(Tier::Iron, Division::IV, 0).score(), 0);
+        assert_eq!(rank(Tier::Silver, Division::II, 40).score(), 1040);
+        assert_eq!(rank(Tier::Emerald, Division::IV, 0).score(), 2000);
+        assert_eq!(rank(Tier::Diamond, Division::I, 50).score(), 2750);
+        assert_eq!(rank(Tier::GrandMaster, Division::I, 300).score(), 3100);
+        assert_eq!(rank(Tier::Gold, Division::V, 10).score(), 1210);
+
+        let grandmaster = rank(Tier::GrandMaster, Division::I, 0);
+        let master = rank(Tier::Master, Division::I, 500);
+        assert!(grandmaster > master);
+        assert!(grandmaster.score() < master.score());
+        assert_eq!(
+            Rank::from_score(grandmaster.score()),
+            rank(Tier::Master, Division::I, 0)
+        );
+
+        for score in (0..APEX_SCORE + 500).step_by(7) {
+            assert_eq!(Rank::from_score(score).score(), score);
+        }
+        assert_eq!(Rank::from_score(1040), rank(Tier::Silver, Division::II, 40));
+        assert_eq!(
+            Rank::from_score(2150),
+            rank(Tier::Emerald, Division::III, 50)
+        );
+    }
+
+    #[test]
+    fn averages_ranks() {
+        let team = vec![
+            rank(Tier::Gold, Division::IV, 0),
+            rank(Tier::Platinum, Division::IV, 0),
+        ];
+
+        assert_eq!(
+            Rank::average(&team),
+            Some(rank(Tier::Gold, Division::II, 0))
+        );
+        assert_eq!(Rank::average(&[]), None);
+    }
+
+    #[test]
+    fn skips_unknown_tiers_and_divisions() {
+        let adamant = Tier::Unknown("ADAMANT".to_owned());
+        let zero = Division::Unknown("0".to_owned());
+
+        assert_eq!(Rank::new(adamant, Division::I, 0), None);
+        assert_eq!(Rank::new(Tier::Gold, zero.clone(), 0), None);
+        assert_eq!(
+            Rank::new(Tier::Master, zero, 0),
+            Some(rank(Tier::Master, Division::I, 0))
+        );
+    }
+
+    #[test]
+    fn parses_grandmaster() {
+        assert_eq!("GRANDMASTER".parse::<Tier>().unwrap(), Tier::GrandMaster);
+        assert_eq!("GRAND_MASTER".parse::<Tier>().unwrap(), Tier::GrandMaster);
+        assert_eq!(Tier::GrandMaster.to_string(), "GRANDMASTER");
+        assert!(Division::IV < Division::I);
+    }
+}
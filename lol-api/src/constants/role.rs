@@ -0,0 +1,12 @@
+riot_enum! {
+    /// Role a participant was assigned to by the match analysis. Only meaningful in bottom lane,
+    /// where it tells the carry apart from the support.
+    pub enum Role: RoleError::InvalidRole("role") {
+        Solo = "SOLO",
+        Duo = "DUO",
+        DuoCarry = "DUO_CARRY",
+        DuoSupport = "DUO_SUPPORT",
+        None = "NONE",
+        ..Unknown
+    }
+}
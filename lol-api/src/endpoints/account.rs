@@ -0,0 +1,95 @@
+use failure::Error;
+use hyper::rt::Future;
+
+use crate::{
+    constants::WithHost,
+    models::{AccountDTO, ActiveShardDTO, RiotId, ShardGame},
+    RiotApi,
+};
+
+use super::routes::{
+    ACCOUNT_V1_GET_ACTIVE_SHARD, ACCOUNT_V1_GET_BY_ACCESS_TOKEN, ACCOUNT_V1_GET_BY_PUUID,
+    ACCOUNT_V1_GET_BY_RIOT_ID,
+};
+
+/// AccountV1 endpoints. Served from the regional hosts, see `Regions`.
+///
+/// <a href="https://developer.riotgames.com/apis#account-v1">Official API Documentation</a>
+pub struct AccountV1<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> AccountV1<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get account by puuid.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#account-v1/GET_getByPuuid">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_by_puuid<T: WithHost>(
+        &self,
+        region: T,
+        puuid: &str,
+    ) -> impl Future<Item = AccountDTO, Error = Error> {
+        self.api.get(region, &ACCOUNT_V1_GET_BY_PUUID, &[puuid])
+    }
+
+    /// Get account by Riot ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#account-v1/GET_getByRiotId">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_by_riot_id<T: WithHost>(
+        &self,
+        region: T,
+        riot_id: &RiotId,
+    ) -> impl Future<Item = AccountDTO, Error = Error> {
+        self.api.get(
+            region,
+            &ACCOUNT_V1_GET_BY_RIOT_ID,
+            &[riot_id.game_name(), riot_id.tag_line()],
+        )
+    }
+
+    /// Get active shard for a player.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#account-v1/GET_getActiveShard">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_active_shard<T: WithHost>(
+        &self,
+        region: T,
+        game: ShardGame,
+        puuid: &str,
+    ) -> impl Future<Item = ActiveShardDTO, Error = Error> {
+        self.api
+            .get(region, &ACCOUNT_V1_GET_ACTIVE_SHARD, &[game.value(), puuid])
+    }
+
+    /// Get the account signed in with Riot Sign-On, using the client's `TokenProvider`.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#account-v1/GET_getByAccessToken">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_me<T: WithHost>(&self, region: T) -> impl Future<Item = AccountDTO, Error = Error> {
+        self.api
+            .get_with_rso(region, &ACCOUNT_V1_GET_BY_ACCESS_TOKEN, &[])
+    }
+
+    /// Get the account the given RSO access token belongs to.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#account-v1/GET_getByAccessToken">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_me_with_token<T: WithHost>(
+        &self,
+        region: T,
+        access_token: &str,
+    ) -> impl Future<Item = AccountDTO, Error = Error> {
+        self.api
+            .get_with_token(region, &ACCOUNT_V1_GET_BY_ACCESS_TOKEN, &[], access_token)
+    }
+}
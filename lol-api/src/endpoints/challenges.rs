@@ -0,0 +1,146 @@
+use failure::Error;
+use hyper::rt::Future;
+use serde_derive::Serialize;
+use std::collections::HashMap;
+
+use crate::{
+    constants::{ChallengeLevel, WithHost},
+    models::{ApexPlayerInfoDTO, ChallengeConfigInfoDTO, PlayerInfoDTO},
+    RiotApi,
+};
+
+use super::{
+    routes::{
+        CHALLENGES_V1_GET_ALL_CHALLENGE_CONFIGS, CHALLENGES_V1_GET_ALL_CHALLENGE_PERCENTILES,
+        CHALLENGES_V1_GET_CHALLENGE_CONFIGS, CHALLENGES_V1_GET_CHALLENGE_LEADERBOARDS,
+        CHALLENGES_V1_GET_CHALLENGE_PERCENTILES, CHALLENGES_V1_GET_PLAYER_DATA,
+    },
+    to_query,
+};
+
+/// ChallengesV1 endpoints.
+///
+/// <a href="https://developer.riotgames.com/apis#lol-challenges-v1">Official API Documentation</a>
+pub struct ChallengesV1<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> ChallengesV1<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// List of all basic challenge configuration information (includes all translations for
+    /// names and descriptions).
+    ///
+    /// <a href="https://developer.riotgames.com/apis#lol-challenges-v1/GET_getAllChallengeConfigs">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_all_challenge_configs<T: WithHost>(
+        &self,
+        region: T,
+    ) -> impl Future<Item = Vec<ChallengeConfigInfoDTO>, Error = Error> {
+        self.api
+            .get(region, &CHALLENGES_V1_GET_ALL_CHALLENGE_CONFIGS, &[])
+    }
+
+    /// Map of level to percentile of players who have achieved it, keyed by challenge ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#lol-challenges-v1/GET_getAllChallengePercentiles">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_all_challenge_percentiles<T: WithHost>(
+        &self,
+        region: T,
+    ) -> impl Future<Item = HashMap<i64, HashMap<ChallengeLevel, f64>>, Error = Error> {
+        self.api
+            .get(region, &CHALLENGES_V1_GET_ALL_CHALLENGE_PERCENTILES, &[])
+    }
+
+    /// Get challenge configuration.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#lol-challenges-v1/GET_getChallengeConfigs">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_challenge_configs<T: WithHost>(
+        &self,
+        region: T,
+        challenge_id: i64,
+    ) -> impl Future<Item = ChallengeConfigInfoDTO, Error = Error> {
+        self.api.get(
+            region,
+            &CHALLENGES_V1_GET_CHALLENGE_CONFIGS,
+            &[&challenge_id.to_string()],
+        )
+    }
+
+    /// Return top players for each level. Only `Master`, `GrandMaster` and `Challenger` have a
+    /// leaderboard.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#lol-challenges-v1/GET_getChallengeLeaderboards">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_challenge_leaderboards<T: WithHost>(
+        &self,
+        region: T,
+        challenge_id: i64,
+        level: ChallengeLevel,
+        parameters: GetChallengeLeaderboardsParams,
+    ) -> impl Future<Item = Vec<ApexPlayerInfoDTO>, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &CHALLENGES_V1_GET_CHALLENGE_LEADERBOARDS,
+            &[&challenge_id.to_string(), level.value()],
+            to_query(&parameters),
+        )
+    }
+
+    /// Map of level to percentile of players who have achieved it.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#lol-challenges-v1/GET_getChallengePercentiles">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_challenge_percentiles<T: WithHost>(
+        &self,
+        region: T,
+        challenge_id: i64,
+    ) -> impl Future<Item = HashMap<ChallengeLevel, f64>, Error = Error> {
+        self.api.get(
+            region,
+            &CHALLENGES_V1_GET_CHALLENGE_PERCENTILES,
+            &[&challenge_id.to_string()],
+        )
+    }
+
+    /// Returns player information with list of all progressed challenges.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#lol-challenges-v1/GET_getPlayerData">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_player_data<T: WithHost>(
+        &self,
+        region: T,
+        puuid: &str,
+    ) -> impl Future<Item = PlayerInfoDTO, Error = Error> {
+        self.api
+            .get(region, &CHALLENGES_V1_GET_PLAYER_DATA, &[puuid])
+    }
+}
+
+/// Query parameters of `ChallengesV1::get_challenge_leaderboards`.
+#[derive(Serialize, Default, Debug)]
+pub struct GetChallengeLeaderboardsParams {
+    limit: Option<u32>,
+}
+
+impl GetChallengeLeaderboardsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum number of players to return for the level.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
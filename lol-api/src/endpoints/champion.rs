@@ -3,7 +3,7 @@ use hyper::rt::Future;
 
 use crate::{constants::WithHost, models::ChampionInfo, RiotApi};
 
-use super::CHAMPION_ROTATIONS_PATH;
+use super::routes::CHAMPION_V3_GET_CHAMPION_INFO;
 
 /// ChampionV3 endpoints.
 ///
@@ -27,6 +27,6 @@ impl<'a> ChampionV3<'a> {
         &self,
         region: T,
     ) -> impl Future<Item = ChampionInfo, Error = Error> {
-        self.api.get(region, CHAMPION_ROTATIONS_PATH.to_string())
+        self.api.get(region, &CHAMPION_V3_GET_CHAMPION_INFO, &[])
     }
 }
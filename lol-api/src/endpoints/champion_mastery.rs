@@ -1,8 +1,22 @@
 use failure::Error;
 use hyper::rt::Future;
+use serde_derive::Serialize;
 
-use super::{CHAMPION_MASTERY_PATH, SCORE_MASTERY_PATH};
-use crate::{constants::WithHost, models::ChampionMasteryDTO, RiotApi};
+use super::routes::{
+    CHAMPION_MASTERY_V4_GET_ALL_CHAMPION_MASTERIES,
+    CHAMPION_MASTERY_V4_GET_ALL_CHAMPION_MASTERIES_BY_PUUID,
+    CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY, CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_BY_PUUID,
+    CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_SCORE,
+    CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_SCORE_BY_PUUID,
+    CHAMPION_MASTERY_V4_GET_TOP_CHAMPION_MASTERIES,
+    CHAMPION_MASTERY_V4_GET_TOP_CHAMPION_MASTERIES_BY_PUUID,
+};
+use super::to_query;
+use crate::{
+    constants::{Champion, WithHost},
+    models::ChampionMasteryDTO,
+    RiotApi,
+};
 
 /// ChampionMasteryV4 endpoints.
 ///
@@ -26,8 +40,11 @@ impl<'a> ChampionMasteryV4<'a> {
         region: T,
         encrypted_summoner_id: &str,
     ) -> impl Future<Item = Vec<ChampionMasteryDTO>, Error = Error> {
-        let path = format!("{}/by-summoner/{}", CHAMPION_MASTERY_PATH, encrypted_summoner_id);
-        self.api.get(region, path)
+        self.api.get(
+            region,
+            &CHAMPION_MASTERY_V4_GET_ALL_CHAMPION_MASTERIES,
+            &[encrypted_summoner_id],
+        )
     }
 
     /// Get a champion mastery by player ID and champion ID
@@ -39,13 +56,13 @@ impl<'a> ChampionMasteryV4<'a> {
         &self,
         region: T,
         encrypted_summoner_id: &str,
-        champion_id: u32,
+        champion: Champion,
     ) -> impl Future<Item = ChampionMasteryDTO, Error = Error> {
-        let path = format!(
-            "{}/by-summoner/{}/by-champion/{}",
-            CHAMPION_MASTERY_PATH, encrypted_summoner_id, champion_id,
-        );
-        self.api.get(region, path)
+        self.api.get(
+            region,
+            &CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY,
+            &[encrypted_summoner_id, &champion.id().to_string()],
+        )
     }
 
     /// Get a player's total champion mastery score, which is the sum of individual champion
@@ -59,8 +76,121 @@ impl<'a> ChampionMasteryV4<'a> {
         region: T,
         encrypted_summoner_id: &str,
     ) -> impl Future<Item = u32, Error = Error> {
-        let path = format!("{}/by-summoner/{}", SCORE_MASTERY_PATH, encrypted_summoner_id,);
+        self.api.get(
+            region,
+            &CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_SCORE,
+            &[encrypted_summoner_id],
+        )
+    }
+
+    /// Get the specified number of top champion mastery entries sorted by number of champion
+    /// points descending.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#champion-mastery-v4/GET_getTopChampionMasteries">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_top_champion_masteries<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_summoner_id: &str,
+        parameters: GetTopChampionMasteriesParams,
+    ) -> impl Future<Item = Vec<ChampionMasteryDTO>, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &CHAMPION_MASTERY_V4_GET_TOP_CHAMPION_MASTERIES,
+            &[encrypted_summoner_id],
+            to_query(&parameters),
+        )
+    }
+
+    /// Get all champion mastery entries sorted by number of champion points descending.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#champion-mastery-v4/GET_getAllChampionMasteriesByPUUID">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_all_champion_masteries_by_puuid<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_puuid: &str,
+    ) -> impl Future<Item = Vec<ChampionMasteryDTO>, Error = Error> {
+        self.api.get(
+            region,
+            &CHAMPION_MASTERY_V4_GET_ALL_CHAMPION_MASTERIES_BY_PUUID,
+            &[encrypted_puuid],
+        )
+    }
+
+    /// Get a champion mastery by PUUID and champion ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#champion-mastery-v4/GET_getChampionMasteryByPUUID">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_champion_mastery_by_puuid<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_puuid: &str,
+        champion: Champion,
+    ) -> impl Future<Item = ChampionMasteryDTO, Error = Error> {
+        self.api.get(
+            region,
+            &CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_BY_PUUID,
+            &[encrypted_puuid, &champion.id().to_string()],
+        )
+    }
+
+    /// Get the specified number of top champion mastery entries sorted by number of champion
+    /// points descending.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#champion-mastery-v4/GET_getTopChampionMasteriesByPUUID">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_top_champion_masteries_by_puuid<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_puuid: &str,
+        parameters: GetTopChampionMasteriesParams,
+    ) -> impl Future<Item = Vec<ChampionMasteryDTO>, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &CHAMPION_MASTERY_V4_GET_TOP_CHAMPION_MASTERIES_BY_PUUID,
+            &[encrypted_puuid],
+            to_query(&parameters),
+        )
+    }
+
+    /// Get a player's total champion mastery score, which is the sum of individual champion
+    /// mastery levels.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#champion-mastery-v4/GET_getChampionMasteryScoreByPUUID">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_champion_mastery_score_by_puuid<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_puuid: &str,
+    ) -> impl Future<Item = u32, Error = Error> {
+        self.api.get(
+            region,
+            &CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_SCORE_BY_PUUID,
+            &[encrypted_puuid],
+        )
+    }
+}
+
+/// Query parameters of the top champion masteries endpoints.
+#[derive(Serialize, Default, Debug)]
+pub struct GetTopChampionMasteriesParams {
+    count: Option<u32>,
+}
+
+impl GetTopChampionMasteriesParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        self.api.get(region, path)
+    /// Number of entries to retrieve, defaults to 3.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
     }
 }
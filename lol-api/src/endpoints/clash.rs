@@ -0,0 +1,97 @@
+use failure::Error;
+use hyper::rt::Future;
+
+use crate::{
+    constants::WithHost,
+    models::{ClashPlayerDTO, ClashTeamDTO, ClashTournamentDTO},
+    RiotApi,
+};
+
+use super::routes::{
+    CLASH_V1_GET_PLAYERS_BY_SUMMONER, CLASH_V1_GET_TEAM_BY_ID, CLASH_V1_GET_TOURNAMENTS,
+    CLASH_V1_GET_TOURNAMENT_BY_ID, CLASH_V1_GET_TOURNAMENT_BY_TEAM,
+};
+
+/// ClashV1 endpoints.
+///
+/// <a href="https://developer.riotgames.com/apis#clash-v1">Official API Documentation</a>
+pub struct ClashV1<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> ClashV1<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get players by summoner ID. Returns a list of active Clash players, one for each tournament
+    /// the summoner is registered in.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#clash-v1/GET_getPlayersBySummoner">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_players_by_summoner<T: WithHost>(
+        &self,
+        region: T,
+        summoner_id: &str,
+    ) -> impl Future<Item = Vec<ClashPlayerDTO>, Error = Error> {
+        self.api
+            .get(region, &CLASH_V1_GET_PLAYERS_BY_SUMMONER, &[summoner_id])
+    }
+
+    /// Get team by ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#clash-v1/GET_getTeamById">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_team_by_id<T: WithHost>(
+        &self,
+        region: T,
+        team_id: &str,
+    ) -> impl Future<Item = ClashTeamDTO, Error = Error> {
+        self.api.get(region, &CLASH_V1_GET_TEAM_BY_ID, &[team_id])
+    }
+
+    /// Get all active or upcoming tournaments.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#clash-v1/GET_getTournaments">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_tournaments<T: WithHost>(
+        &self,
+        region: T,
+    ) -> impl Future<Item = Vec<ClashTournamentDTO>, Error = Error> {
+        self.api.get(region, &CLASH_V1_GET_TOURNAMENTS, &[])
+    }
+
+    /// Get tournament by team ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#clash-v1/GET_getTournamentByTeam">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_tournament_by_team<T: WithHost>(
+        &self,
+        region: T,
+        team_id: &str,
+    ) -> impl Future<Item = ClashTournamentDTO, Error = Error> {
+        self.api
+            .get(region, &CLASH_V1_GET_TOURNAMENT_BY_TEAM, &[team_id])
+    }
+
+    /// Get tournament by ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#clash-v1/GET_getTournamentById">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_tournament_by_id<T: WithHost>(
+        &self,
+        region: T,
+        tournament_id: u32,
+    ) -> impl Future<Item = ClashTournamentDTO, Error = Error> {
+        self.api.get(
+            region,
+            &CLASH_V1_GET_TOURNAMENT_BY_ID,
+            &[&tournament_id.to_string()],
+        )
+    }
+}
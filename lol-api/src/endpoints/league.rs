@@ -1,8 +1,6 @@
 use failure::Error;
 use hyper::rt::Future;
-use strum::IntoEnumIterator;
-use strum_macros::EnumIter;
-use url::form_urlencoded::Serializer;
+use serde_derive::Serialize;
 
 use crate::{
     constants::{Division, Queue, Tier, WithHost},
@@ -10,7 +8,14 @@ use crate::{
     RiotApi,
 };
 
-use super::LEAGUE_PATH;
+use super::{
+    routes::{
+        LEAGUE_V4_GET_CHALLENGER_LEAGUE, LEAGUE_V4_GET_GRANDMASTER_LEAGUE,
+        LEAGUE_V4_GET_LEAGUE_BY_ID, LEAGUE_V4_GET_LEAGUE_ENTRIES,
+        LEAGUE_V4_GET_LEAGUE_ENTRIES_FOR_SUMMONER, LEAGUE_V4_GET_MASTER_LEAGUE,
+    },
+    to_query,
+};
 
 /// LeagueV4 endpoints.
 ///
@@ -34,9 +39,8 @@ impl<'a> LeagueV4<'a> {
         region: T,
         queue: Queue,
     ) -> impl Future<Item = LeagueListDTO, Error = Error> {
-        let path = format!("{}/challengerleagues/by-queue/{}", LEAGUE_PATH, queue);
-
-        self.api.get(region, path)
+        self.api
+            .get(region, &LEAGUE_V4_GET_CHALLENGER_LEAGUE, &[queue.value()])
     }
 
     /// Get the grandmaster league of a specific queue.
@@ -49,9 +53,8 @@ impl<'a> LeagueV4<'a> {
         region: T,
         queue: Queue,
     ) -> impl Future<Item = LeagueListDTO, Error = Error> {
-        let path = format!("{}/grandmasterleagues/by-queue/{}", LEAGUE_PATH, queue);
-
-        self.api.get(region, path)
+        self.api
+            .get(region, &LEAGUE_V4_GET_GRANDMASTER_LEAGUE, &[queue.value()])
     }
 
     /// Get the master league for given queue.
@@ -64,9 +67,8 @@ impl<'a> LeagueV4<'a> {
         region: T,
         queue: Queue,
     ) -> impl Future<Item = LeagueListDTO, Error = Error> {
-        let path = format!("{}/masterleagues/by-queue/{}", LEAGUE_PATH, queue);
-
-        self.api.get(region, path)
+        self.api
+            .get(region, &LEAGUE_V4_GET_MASTER_LEAGUE, &[queue.value()])
     }
 
     /// Get league with given ID, including inactive entries.
@@ -79,9 +81,8 @@ impl<'a> LeagueV4<'a> {
         region: T,
         league_id: &str,
     ) -> impl Future<Item = LeagueListDTO, Error = Error> {
-        let path = format!("{}/leagues/{}", LEAGUE_PATH, league_id);
-
-        self.api.get(region, path)
+        self.api
+            .get(region, &LEAGUE_V4_GET_LEAGUE_BY_ID, &[league_id])
     }
 
     /// Get league entries in all queues for a given summoner ID.
@@ -94,9 +95,11 @@ impl<'a> LeagueV4<'a> {
         region: T,
         summoner_id: &str,
     ) -> impl Future<Item = Vec<LeagueEntryDTO>, Error = Error> {
-        let path = format!("{}/entries/by-summoner/{}", LEAGUE_PATH, summoner_id);
-
-        self.api.get(region, path)
+        self.api.get(
+            region,
+            &LEAGUE_V4_GET_LEAGUE_ENTRIES_FOR_SUMMONER,
+            &[summoner_id],
+        )
     }
 
     /// Get all the league entries.
@@ -112,29 +115,29 @@ impl<'a> LeagueV4<'a> {
         division: Division,
         parameters: GetLeagueEntriesParams,
     ) -> impl Future<Item = Vec<LeagueEntryDTO>, Error = Error> {
-        let path = format!("{}/entries/{}/{}/{}", LEAGUE_PATH, queue, tier, division);
-
-        let mut query_params = Serializer::new(String::new());
-        for parameter in LeagueParams::iter() {
-            match parameter {
-                LeagueParams::Page => match &parameters.page {
-                    Some(page) => {
-                        query_params.append_pair("page", &page.to_string());
-                    }
-                    None => {}
-                }
-            }
-        }
-
-        self.api.get_with_params(region, path, query_params.finish())
+        self.api.get_with_params(
+            region,
+            &LEAGUE_V4_GET_LEAGUE_ENTRIES,
+            &[queue.value(), tier.value(), division.value()],
+            to_query(&parameters),
+        )
     }
 }
 
+/// Query parameters of the league entries endpoints.
+#[derive(Serialize, Default, Debug)]
 pub struct GetLeagueEntriesParams {
     page: Option<u32>,
 }
 
-#[derive(EnumIter, Debug)]
-enum LeagueParams {
-    Page,
+impl GetLeagueEntriesParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Page to fetch, starting at 1.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
 }
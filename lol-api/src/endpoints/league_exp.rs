@@ -0,0 +1,44 @@
+use failure::Error;
+use hyper::rt::Future;
+
+use crate::{
+    constants::{Division, Queue, Tier, WithHost},
+    models::LeagueEntryDTO,
+    RiotApi,
+};
+
+use super::{routes::LEAGUE_EXP_V4_GET_LEAGUE_ENTRIES, to_query, GetLeagueEntriesParams};
+
+/// LeagueExpV4 endpoints.
+///
+/// <a href="https://developer.riotgames.com/apis#league-exp-v4">Official API Documentation</a>
+pub struct LeagueExpV4<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> LeagueExpV4<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get all the league entries, apex tiers included. Apex tiers only have `Division::I`.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#league-exp-v4/GET_getLeagueEntries">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_league_entries<T: WithHost>(
+        &self,
+        region: T,
+        queue: Queue,
+        tier: Tier,
+        division: Division,
+        parameters: GetLeagueEntriesParams,
+    ) -> impl Future<Item = Vec<LeagueEntryDTO>, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &LEAGUE_EXP_V4_GET_LEAGUE_ENTRIES,
+            &[queue.value(), tier.value(), division.value()],
+            to_query(&parameters),
+        )
+    }
+}
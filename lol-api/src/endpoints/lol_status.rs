@@ -0,0 +1,31 @@
+use failure::Error;
+use hyper::rt::Future;
+
+use crate::{constants::WithHost, models::PlatformDataDTO, RiotApi};
+
+use super::routes::LOL_STATUS_V4_GET_PLATFORM_DATA;
+
+/// LolStatusV4 endpoints.
+///
+/// <a href="https://developer.riotgames.com/apis#lol-status-v4">Official API Documentation</a>
+pub struct LolStatusV4<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> LolStatusV4<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get League of Legends status for the given platform.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#lol-status-v4/GET_getPlatformData">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_platform_data<T: WithHost>(
+        &self,
+        region: T,
+    ) -> impl Future<Item = PlatformDataDTO, Error = Error> {
+        self.api.get(region, &LOL_STATUS_V4_GET_PLATFORM_DATA, &[])
+    }
+}
@@ -1,16 +1,31 @@
+use chrono::serde::ts_milliseconds_option;
+use chrono::{DateTime, Duration, Utc};
 use failure::Error;
+use futures::future::{self, Either, Loop};
 use hyper::rt::Future;
-use strum::IntoEnumIterator;
-use strum_macros::EnumIter;
-use url::form_urlencoded::Serializer;
+use serde_derive::Serialize;
+use std::collections::{HashSet, VecDeque};
 
 use crate::{
-    constants::{Queue, WithHost},
-    models::{MatchDTO, MatchTimelineDTO, MatchlistDTO},
-    RiotApi,
+    constants::{Champion, WithHost},
+    models::{MatchDTO, MatchReferenceDTO, MatchTimelineDTO, MatchlistDTO},
+    riot_api::optional,
+    FetchError, RiotApi,
 };
 
-use super::MATCH_V4_PATH;
+use super::{
+    routes::{
+        MATCH_V4_GET_MATCH, MATCH_V4_GET_MATCHLIST, MATCH_V4_GET_MATCH_BY_TOURNAMENT_CODE,
+        MATCH_V4_GET_MATCH_IDS_BY_TOURNAMENT_CODE, MATCH_V4_GET_MATCH_TIMELINE,
+    },
+    to_query,
+};
+
+/// Longest time span, in weeks, accepted by the matchlist endpoint.
+const MAX_MATCHLIST_WEEKS: i64 = 1;
+
+/// Largest index range accepted by the matchlist endpoint.
+const MAX_MATCHLIST_INDEX_RANGE: u32 = 100;
 
 /// MatchV4 endpoints
 ///
@@ -34,12 +49,11 @@ impl<'a> MatchV4<'a> {
         region: T,
         tournament_code: &str,
     ) -> impl Future<Item = Vec<u64>, Error = Error> {
-        let path = format!(
-            "{}/by-tournament-code/{}/ids",
-            MATCH_V4_PATH, tournament_code
-        );
-
-        self.api.get(region, path)
+        self.api.get(
+            region,
+            &MATCH_V4_GET_MATCH_IDS_BY_TOURNAMENT_CODE,
+            &[tournament_code],
+        )
     }
 
     /// Get match by match ID.
@@ -52,9 +66,7 @@ impl<'a> MatchV4<'a> {
         region: T,
         match_id: &str,
     ) -> impl Future<Item = MatchDTO, Error = Error> {
-        let path = format!("{}/matches/{}", MATCH_V4_PATH, match_id,);
-
-        self.api.get(region, path)
+        self.api.get(region, &MATCH_V4_GET_MATCH, &[match_id])
     }
 
     /// Get match by match ID and tournament Code.
@@ -68,16 +80,16 @@ impl<'a> MatchV4<'a> {
         match_id: &str,
         tournament_code: &str,
     ) -> impl Future<Item = MatchDTO, Error = Error> {
-        let path = format!(
-            "{}/matches/{}/by-tournament-code/{}",
-            MATCH_V4_PATH, match_id, tournament_code
-        );
-
-        self.api.get(region, path)
+        self.api.get(
+            region,
+            &MATCH_V4_GET_MATCH_BY_TOURNAMENT_CODE,
+            &[match_id, tournament_code],
+        )
     }
 
     /// Get matchlist for games played given account ID and platform ID and filtered using given
-    /// fulter parameters, if any.
+    /// fulter parameters, if any. Parameters breaking the limits of the endpoint are rejected
+    /// before sending the request, see `GetMatchlistParameters::validate`.
     ///
     /// <a href="https://developer.riotgames.com/apis#match-v4/GET_getMatchlist">
     ///   Official API Documentation
@@ -88,56 +100,93 @@ impl<'a> MatchV4<'a> {
         encrypted_account_id: &str,
         parameters: GetMatchlistParameters,
     ) -> impl Future<Item = MatchlistDTO, Error = Error> {
-        let path = format!(
-            "{}/matchlists/by-account/{}",
-            MATCH_V4_PATH, encrypted_account_id
-        );
+        if let Err(err) = parameters.validate() {
+            return Either::B(future::err(err.into()));
+        }
 
-        let mut query_params = Serializer::new(String::new());
-        for parameter in MatchlistParameters::iter() {
-            match parameter {
-                MatchlistParameters::Champions => match &parameters.champions {
-                    Some(champions) => {
-                        query_params
-                            .extend_pairs(champions.iter().map(|c| ("champion", c.to_string())));
-                    }
-                    None => {}
-                },
-                MatchlistParameters::Queues => match &parameters.queues {
-                    Some(queues) => {
-                        query_params.extend_pairs(queues.iter().map(|q| ("queue", q.to_string())));
-                    }
-                    None => {}
-                },
-                MatchlistParameters::BeginIndex => match &parameters.begin_index {
-                    Some(begin_index) => {
-                        query_params.append_pair("beginIndex", &begin_index.to_string());
-                    }
-                    None => {}
-                },
-                MatchlistParameters::EndIndex => match &parameters.end_index {
-                    Some(end_index) => {
-                        query_params.append_pair("endIndex", &end_index.to_string());
-                    }
-                    None => {}
-                },
-                MatchlistParameters::BeginTime => match &parameters.begin_time {
-                    Some(begin_time) => {
-                        query_params.append_pair("beginTime", &begin_time.to_string());
-                    }
-                    None => {}
-                },
-                MatchlistParameters::EndTime => match &parameters.end_time {
-                    Some(end_time) => {
-                        query_params.append_pair("endTime", &end_time.to_string());
-                    }
-                    None => {}
-                },
+        Either::A(self.api.get_with_params(
+            region,
+            &MATCH_V4_GET_MATCHLIST,
+            &[encrypted_account_id],
+            to_query(&parameters),
+        ))
+    }
+
+    /// Get every match played between the begin and end times of `parameters`, which are both
+    /// required. The time range is split into windows of one week, each one fetched page by page,
+    /// and matches returned by several windows are only kept once. Windows without any match,
+    /// which Riot answers with `404`, are skipped. The index parameters are ignored.
+    pub fn get_full_matchlist<T>(
+        &self,
+        region: T,
+        encrypted_account_id: &str,
+        parameters: GetMatchlistParameters,
+    ) -> impl Future<Item = Vec<MatchReferenceDTO>, Error = Error> + 'a
+    where
+        T: WithHost + Copy + 'a,
+    {
+        let windows = match (parameters.begin_time, parameters.end_time) {
+            (Some(begin_time), Some(end_time)) if begin_time <= end_time => {
+                matchlist_windows(begin_time, end_time)
+            }
+            _ => {
+                return Either::B(future::err(
+                    FetchError::InvalidRequest(String::from(
+                        "full matchlist requires a begin time before the end time",
+                    ))
+                    .into(),
+                ))
+            }
+        };
+
+        let api = self.api;
+        let account_id = encrypted_account_id.to_owned();
+        let state = FullMatchlist {
+            windows,
+            begin_index: 0,
+            matches: Vec::new(),
+            game_ids: HashSet::new(),
+        };
+
+        Either::A(future::loop_fn(state, move |mut state| {
+            let (begin_time, end_time) = match state.windows.front() {
+                Some(window) => *window,
+                None => return Either::B(future::ok(Loop::Break(state.matches))),
             };
-        }
+            let page = parameters
+                .clone()
+                .begin_time(begin_time)
+                .end_time(end_time)
+                .begin_index(state.begin_index)
+                .end_index(state.begin_index + MAX_MATCHLIST_INDEX_RANGE);
 
-        self.api
-            .get_with_params(region, path, query_params.finish())
+            Either::A(
+                optional(MatchV4::new(api).get_matchlist(region, &account_id, page)).map(
+                    move |matchlist| {
+                        let matchlist = match matchlist {
+                            Some(matchlist) => matchlist,
+                            None => {
+                                state.windows.pop_front();
+                                state.begin_index = 0;
+                                return Loop::Continue(state);
+                            }
+                        };
+                        let last_page = matchlist.matches.len()
+                            < MAX_MATCHLIST_INDEX_RANGE as usize
+                            || matchlist.end_index >= matchlist.total_games;
+                        state.add(matchlist.matches);
+
+                        if last_page {
+                            state.windows.pop_front();
+                            state.begin_index = 0;
+                        } else {
+                            state.begin_index += MAX_MATCHLIST_INDEX_RANGE;
+                        }
+                        Loop::Continue(state)
+                    },
+                ),
+            )
+        }))
     }
 
     /// Get match timeline by match ID.
@@ -150,27 +199,320 @@ impl<'a> MatchV4<'a> {
         region: T,
         match_id: &str,
     ) -> impl Future<Item = MatchTimelineDTO, Error = Error> {
-        let path = format!("{}/timelines/by-match/{}", MATCH_V4_PATH, match_id,);
-
-        self.api.get(region, path)
+        self.api
+            .get(region, &MATCH_V4_GET_MATCH_TIMELINE, &[match_id])
     }
 }
 
+/// Filters of `MatchV4::get_matchlist`. Every filter is optional.
+#[derive(Serialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct GetMatchlistParameters {
-    pub champions: Option<Vec<i32>>, // TODO: Implement Champion constant
-    pub queues: Option<Vec<Queue>>,
-    pub end_time: Option<u64>,
-    pub begin_time: Option<u64>,
-    pub end_index: Option<u64>,
-    pub begin_index: Option<u64>,
+    #[serde(rename = "champion")]
+    champions: Vec<Champion>,
+    #[serde(rename = "queue")]
+    queues: Vec<u32>,
+    #[serde(with = "ts_milliseconds_option")]
+    end_time: Option<DateTime<Utc>>,
+    #[serde(with = "ts_milliseconds_option")]
+    begin_time: Option<DateTime<Utc>>,
+    end_index: Option<u32>,
+    begin_index: Option<u32>,
+}
+
+impl GetMatchlistParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks the limits enforced by the matchlist endpoint: an end time requires a begin time,
+    /// it can't be before the begin time nor more than one week after it, and at most 100 matches
+    /// can be requested at once.
+    pub fn validate(&self) -> Result<(), FetchError> {
+        if let (None, Some(_)) = (self.begin_time, self.end_time) {
+            return Err(FetchError::InvalidRequest(String::from(
+                "matchlist end time requires a begin time",
+            )));
+        }
+        if let (Some(begin_time), Some(end_time)) = (self.begin_time, self.end_time) {
+            if end_time < begin_time {
+                return Err(FetchError::InvalidRequest(String::from(
+                    "matchlist end time is before its begin time",
+                )));
+            }
+            if end_time - begin_time > Duration::weeks(MAX_MATCHLIST_WEEKS) {
+                return Err(FetchError::InvalidRequest(String::from(
+                    "matchlist time range is longer than one week",
+                )));
+            }
+        }
+
+        let begin_index = self.begin_index.unwrap_or(0);
+        if let Some(end_index) = self.end_index {
+            if end_index < begin_index {
+                return Err(FetchError::InvalidRequest(String::from(
+                    "matchlist end index is before its begin index",
+                )));
+            }
+            if end_index - begin_index > MAX_MATCHLIST_INDEX_RANGE {
+                return Err(FetchError::InvalidRequest(format!(
+                    "matchlist index range is larger than {}",
+                    MAX_MATCHLIST_INDEX_RANGE
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Only matches played with the given champion. Can be repeated.
+    pub fn champion(mut self, champion: Champion) -> Self {
+        self.champions.push(champion);
+        self
+    }
+
+    /// Only matches of the given queue id, like `420` for ranked solo, see the Game Constants
+    /// documentation. Can be repeated.
+    pub fn queue(mut self, queue: u32) -> Self {
+        self.queues.push(queue);
+        self
+    }
+
+    /// Only matches played before the given time.
+    pub fn end_time(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Only matches played after the given time.
+    pub fn begin_time(mut self, begin_time: DateTime<Utc>) -> Self {
+        self.begin_time = Some(begin_time);
+        self
+    }
+
+    /// Index of the last match to return, exclusive.
+    pub fn end_index(mut self, end_index: u32) -> Self {
+        self.end_index = Some(end_index);
+        self
+    }
+
+    /// Index of the first match to return.
+    pub fn begin_index(mut self, begin_index: u32) -> Self {
+        self.begin_index = Some(begin_index);
+        self
+    }
 }
 
-#[derive(EnumIter, Debug)]
-enum MatchlistParameters {
-    Champions,
-    Queues,
-    EndTime,
-    BeginTime,
-    EndIndex,
-    BeginIndex,
+/// Progress of `MatchV4::get_full_matchlist`.
+struct FullMatchlist {
+    /// Time windows left to fetch, the first one being in progress.
+    windows: VecDeque<(DateTime<Utc>, DateTime<Utc>)>,
+    begin_index: u32,
+    matches: Vec<MatchReferenceDTO>,
+    game_ids: HashSet<u64>,
+}
+
+impl FullMatchlist {
+    /// Keeps the matches not returned by a previous page.
+    fn add(&mut self, matches: Vec<MatchReferenceDTO>) {
+        for m in matches {
+            if self.game_ids.insert(m.game_id) {
+                self.matches.push(m);
+            }
+        }
+    }
+}
+
+/// Splits a time range into consecutive windows accepted by the matchlist endpoint.
+fn matchlist_windows(
+    begin_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+) -> VecDeque<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut windows = VecDeque::new();
+    let mut window_begin = begin_time;
+    loop {
+        let window_end = std::cmp::min(
+            window_begin + Duration::weeks(MAX_MATCHLIST_WEEKS),
+            end_time,
+        );
+        windows.push_back((window_begin, window_end));
+        if window_end >= end_time {
+            return windows;
+        }
+        window_begin = window_end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::Platforms;
+    use crate::RustApiConfig;
+    use chrono::TimeZone;
+    use hyper::service::service_fn_ok;
+    use hyper::{Body, Request, Response, Server};
+    use std::sync::{Arc, Mutex};
+    use tokio::runtime::{current_thread, Runtime};
+    use url::form_urlencoded;
+
+    fn time(days: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_600_000_000, 0).unwrap() + Duration::days(days)
+    }
+
+    /// `(begin day, begin index, end day)` of the requests received by the fake server.
+    type RequestLog = Arc<Mutex<Vec<(i64, u32, i64)>>>;
+
+    /// Days since `time(0)` of a timestamp in milliseconds.
+    fn day(timestamp: &str) -> i64 {
+        (timestamp.parse::<i64>().unwrap() / 1000 - time(0).timestamp()) / 86_400
+    }
+
+    fn match_reference(game_id: u64) -> serde_json::Value {
+        serde_json::json!({
+            "platformId": "EUW1",
+            "gameId": game_id,
+            "champion": 7,
+            "queue": 420,
+            "season": 13,
+            "timestamp": 1_600_000_000_000u64,
+            "role": "SOLO",
+            "lane": "MID"
+        })
+    }
+
+    /// Starts a fake matchlist server. The first week has 101 matches, the second week repeats
+    /// the last one of the first week and adds another, and the last days have no match at all.
+    /// Returns its URL and the `(begin day, begin index, end day)` of every request.
+    fn fake_matchlist_server(rt: &mut Runtime) -> (String, RequestLog) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let log = requests.clone();
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(move || {
+            let log = log.clone();
+            service_fn_ok(move |req: Request<Body>| {
+                let query = req.uri().query().unwrap_or("").to_owned();
+                let param = |name: &str| {
+                    form_urlencoded::parse(query.as_bytes())
+                        .find(|(key, _)| key == name)
+                        .map(|(_, value)| value.into_owned())
+                        .unwrap()
+                };
+                let begin_day = day(&param("beginTime"));
+                let end_day = day(&param("endTime"));
+                let begin_index: u32 = param("beginIndex").parse().unwrap();
+                log.lock().unwrap().push((begin_day, begin_index, end_day));
+
+                let game_ids: Vec<u64> = match begin_day {
+                    0 => (1..=101).collect(),
+                    7 => vec![101, 102],
+                    _ => return Response::builder().status(404).body(Body::empty()).unwrap(),
+                };
+                let matches: Vec<_> = game_ids
+                    .iter()
+                    .skip(begin_index as usize)
+                    .take(MAX_MATCHLIST_INDEX_RANGE as usize)
+                    .map(|game_id| match_reference(*game_id))
+                    .collect();
+                let body = serde_json::json!({
+                    "startIndex": begin_index,
+                    "endIndex": begin_index + matches.len() as u32,
+                    "totalGames": game_ids.len(),
+                    "matches": matches
+                });
+
+                Response::new(Body::from(body.to_string()))
+            })
+        });
+        let url = format!("http://{}", server.local_addr());
+        rt.spawn(server.map_err(|err| panic!("fake matchlist server failed: {}", err)));
+
+        (url, requests)
+    }
+
+    #[test]
+    fn fetches_full_matchlist() {
+        let mut rt = Runtime::new().unwrap();
+        let (url, requests) = fake_matchlist_server(&mut rt);
+        let api =
+            RiotApi::new(RustApiConfig::new(String::from("RGAPI-test"), None).with_base_url(&url));
+        let parameters = GetMatchlistParameters::new()
+            .begin_time(time(0))
+            .end_time(time(17));
+
+        let matches = current_thread::Runtime::new()
+            .unwrap()
+            .block_on(
+                api.match_v4()
+                    .get_full_matchlist(Platforms::Euw1, "account", parameters),
+            )
+            .unwrap();
+
+        let game_ids: Vec<u64> = matches.iter().map(|m| m.game_id).collect();
+        assert_eq!(game_ids, (1..=102).collect::<Vec<_>>());
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![(0, 0, 7), (0, 100, 7), (7, 0, 14), (14, 0, 17)]
+        );
+    }
+
+    #[test]
+    fn validates_time_range() {
+        let valid = GetMatchlistParameters::new()
+            .begin_time(time(0))
+            .end_time(time(7));
+        let too_long = GetMatchlistParameters::new()
+            .begin_time(time(0))
+            .end_time(time(8));
+        let reversed = GetMatchlistParameters::new()
+            .begin_time(time(1))
+            .end_time(time(0));
+
+        assert!(valid.validate().is_ok());
+        assert!(too_long.validate().is_err());
+        assert!(reversed.validate().is_err());
+        assert!(GetMatchlistParameters::new()
+            .begin_time(time(0))
+            .validate()
+            .is_ok());
+        assert!(GetMatchlistParameters::new()
+            .end_time(time(0))
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn validates_index_range() {
+        let valid = GetMatchlistParameters::new()
+            .begin_index(100)
+            .end_index(200);
+        let too_large = GetMatchlistParameters::new()
+            .begin_index(100)
+            .end_index(201);
+
+        assert!(valid.validate().is_ok());
+        assert!(too_large.validate().is_err());
+        assert!(GetMatchlistParameters::new()
+            .end_index(101)
+            .validate()
+            .is_err());
+        assert!(GetMatchlistParameters::new()
+            .begin_index(500)
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn splits_time_range_into_weeks() {
+        let windows: Vec<_> = matchlist_windows(time(0), time(17)).into_iter().collect();
+
+        assert_eq!(
+            windows,
+            vec![
+                (time(0), time(7)),
+                (time(7), time(14)),
+                (time(14), time(17))
+            ]
+        );
+        assert_eq!(matchlist_windows(time(3), time(3)).len(), 1);
+    }
 }
@@ -0,0 +1,132 @@
+use chrono::serde::ts_seconds_option;
+use chrono::{DateTime, Utc};
+use failure::Error;
+use hyper::rt::Future;
+use serde_derive::Serialize;
+
+use crate::{
+    constants::{MatchType, Regions},
+    models::match_v5::{MatchDTO, TimelineDTO},
+    RiotApi,
+};
+
+use super::{
+    routes::{MATCH_V5_GET_MATCH, MATCH_V5_GET_MATCH_IDS_BY_PUUID, MATCH_V5_GET_TIMELINE},
+    to_query,
+};
+
+/// MatchV5 endpoints. Served from the regional hosts, see `Regions`.
+///
+/// <a href="https://developer.riotgames.com/apis#match-v5">Official API Documentation</a>
+pub struct MatchV5<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> MatchV5<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get a list of match ids by puuid, filtered by the given parameters.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#match-v5/GET_getMatchIdsByPUUID">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_match_ids_by_puuid(
+        &self,
+        region: Regions,
+        puuid: &str,
+        parameters: GetMatchIdsParameters,
+    ) -> impl Future<Item = Vec<String>, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &MATCH_V5_GET_MATCH_IDS_BY_PUUID,
+            &[puuid],
+            to_query(&parameters),
+        )
+    }
+
+    /// Get a match by match id, like `EUW1_123`.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#match-v5/GET_getMatch">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_match(
+        &self,
+        region: Regions,
+        match_id: &str,
+    ) -> impl Future<Item = MatchDTO, Error = Error> {
+        self.api.get(region, &MATCH_V5_GET_MATCH, &[match_id])
+    }
+
+    /// Get a match timeline by match id.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#match-v5/GET_getTimeline">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_timeline(
+        &self,
+        region: Regions,
+        match_id: &str,
+    ) -> impl Future<Item = TimelineDTO, Error = Error> {
+        self.api.get(region, &MATCH_V5_GET_TIMELINE, &[match_id])
+    }
+}
+
+/// Filters of `MatchV5::get_match_ids_by_puuid`. Every filter is optional.
+#[derive(Serialize, Default, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMatchIdsParameters {
+    #[serde(with = "ts_seconds_option")]
+    start_time: Option<DateTime<Utc>>,
+    #[serde(with = "ts_seconds_option")]
+    end_time: Option<DateTime<Utc>>,
+    queue: Option<u32>,
+    #[serde(rename = "type")]
+    match_type: Option<MatchType>,
+    start: Option<u32>,
+    count: Option<u32>,
+}
+
+impl GetMatchIdsParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches played after the given time. Riot only keeps this filter accurate for matches
+    /// played after June 16th, 2021.
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Only matches played before the given time.
+    pub fn end_time(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Only matches of the given queue id, see the Game Constants documentation.
+    pub fn queue(mut self, queue: u32) -> Self {
+        self.queue = Some(queue);
+        self
+    }
+
+    /// Only matches of the given type. Mutually inclusive with `queue`.
+    pub fn match_type(mut self, match_type: MatchType) -> Self {
+        self.match_type = Some(match_type);
+        self
+    }
+
+    /// Start index, defaults to 0.
+    pub fn start(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Number of match ids to return, 0 to 100. Defaults to 20.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+}
@@ -1,13 +1,35 @@
+mod account;
+mod challenges;
 mod champion;
 mod champion_mastery;
+mod clash;
 mod league;
-mod paths;
-mod summoner;
+mod league_exp;
+mod lol_status;
 mod r#match;
+mod match_v5;
+mod query;
+pub mod routes;
+mod spectator;
+mod summoner;
+mod tft;
+mod third_party_code;
+mod tournament;
 
+pub use self::account::*;
+pub use self::challenges::*;
 pub use self::champion::*;
 pub use self::champion_mastery::*;
+pub use self::clash::*;
 pub use self::league::*;
-use self::paths::*;
-pub use self::summoner::*;
+pub use self::league_exp::*;
+pub use self::lol_status::*;
+pub use self::match_v5::*;
 pub use self::r#match::*;
+pub use self::spectator::*;
+pub use self::summoner::*;
+pub use self::tft::*;
+pub use self::third_party_code::*;
+pub use self::tournament::*;
+
+pub(crate) use self::query::to_query;
@@ -0,0 +1,76 @@
+//! Serialization of endpoint parameters into query strings.
+
+use serde::Serialize;
+use serde_json::Value;
+use url::form_urlencoded::Serializer;
+
+/// Serializes the fields of `params` into a query string, using the serde field names as keys.
+/// Fields set to `None` are skipped and sequences repeat the key once per item, like
+/// `champion=1&champion=2`. Keys come out in alphabetical order.
+pub(crate) fn to_query<P: Serialize>(params: &P) -> String {
+    let fields = match serde_json::to_value(params) {
+        Ok(Value::Object(fields)) => fields,
+        _ => panic!("query parameters must serialize to a map"),
+    };
+
+    let mut query = Serializer::new(String::new());
+    for (name, value) in fields {
+        append(&mut query, &name, value);
+    }
+
+    query.finish()
+}
+
+fn append(query: &mut Serializer<String>, name: &str, value: Value) {
+    match value {
+        Value::Null => {}
+        Value::Array(items) => {
+            for item in items {
+                append(query, name, item);
+            }
+        }
+        Value::String(value) => {
+            query.append_pair(name, &value);
+        }
+        Value::Object(_) => panic!("nested query parameter: {}", name),
+        value => {
+            query.append_pair(name, &value.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_derive::Serialize;
+
+    #[derive(Serialize, Default)]
+    #[serde(rename_all = "camelCase")]
+    struct Params {
+        begin_index: Option<u32>,
+        #[serde(rename = "champion")]
+        champions: Vec<i16>,
+        name: Option<&'static str>,
+        ranked: Option<bool>,
+    }
+
+    #[test]
+    fn skips_unset_fields() {
+        assert_eq!(to_query(&Params::default()), "");
+    }
+
+    #[test]
+    fn repeats_sequence_keys() {
+        let params = Params {
+            begin_index: Some(100),
+            champions: vec![1, 103],
+            name: Some("Hide on bush"),
+            ranked: Some(true),
+        };
+
+        assert_eq!(
+            to_query(&params),
+            "beginIndex=100&champion=1&champion=103&name=Hide+on+bush&ranked=true"
+        );
+    }
+}
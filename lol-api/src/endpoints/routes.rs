@@ -0,0 +1,1292 @@
+//! Route table describing every endpoint supported by the wrapper.
+//!
+//! URLs, rate limit keys and cache policies are all derived from these routes, so adding an
+//! endpoint starts by describing it here.
+
+use hyper::Method;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::time::Duration;
+
+use crate::constants::{Routing, WithHost};
+use crate::FetchError;
+
+/// Characters left untouched when replacing path placeholders, as defined for unreserved
+/// characters in RFC 3986.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// How long a response can be reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CachePolicy {
+    /// The response must always be fetched.
+    NoCache,
+    /// The response can be reused for the given amount of seconds.
+    Ttl(u64),
+    /// The response never changes, like finished matches.
+    Immutable,
+}
+
+impl CachePolicy {
+    /// Time a response can be reused, `None` when it must not be cached or when it never expires,
+    /// see `is_immutable`.
+    pub fn ttl(&self) -> Option<Duration> {
+        match *self {
+            CachePolicy::Ttl(seconds) => Some(Duration::from_secs(seconds)),
+            CachePolicy::NoCache | CachePolicy::Immutable => None,
+        }
+    }
+
+    /// Whether a response can be reused forever.
+    pub fn is_immutable(&self) -> bool {
+        *self == CachePolicy::Immutable
+    }
+}
+
+/// Credentials an endpoint is called with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Auth {
+    /// The `X-Riot-Token` API key.
+    ApiKey,
+    /// A Riot Sign-On access token, sent as `Authorization: Bearer`. Used by the endpoints acting
+    /// on behalf of the signed in player, like `summoner-v4.getByAccessToken`.
+    Rso,
+}
+
+/// Description of a single endpoint.
+#[derive(Debug)]
+pub struct Route {
+    /// Riot's identifier for the method, like `summoner-v4.getBySummonerName`.
+    pub method_id: &'static str,
+    /// HTTP method used to call the endpoint.
+    pub method: Method,
+    /// Path template, placeholders are written as `{name}`.
+    pub path: &'static str,
+    /// Kind of host serving the endpoint.
+    pub routing: Routing,
+    /// Query parameters accepted by the endpoint.
+    pub query_params: &'static [&'static str],
+    /// How long responses can be cached.
+    pub cache: CachePolicy,
+    /// Credentials the endpoint expects.
+    pub auth: Auth,
+}
+
+impl Route {
+    /// Builds the path replacing the placeholders, in order, with the given arguments. Arguments
+    /// are percent-encoded. Fails when there isn't exactly one argument per placeholder.
+    pub fn build_path(&self, args: &[&str]) -> Result<String, FetchError> {
+        let placeholders = self.path.matches('{').count();
+        if placeholders != args.len() {
+            return Err(FetchError::InvalidRequest(format!(
+                "{} expects {} path arguments, got {}",
+                self.method_id,
+                placeholders,
+                args.len()
+            )));
+        }
+
+        let mut path = String::with_capacity(self.path.len());
+        let mut args = args.iter();
+        let mut rest = self.path;
+
+        while let Some(start) = rest.find('{') {
+            let end = start + rest[start..].find('}').expect("unclosed path placeholder");
+            path.push_str(&rest[..start]);
+            if let Some(arg) = args.next() {
+                path.extend(utf8_percent_encode(arg, PATH_SEGMENT));
+            }
+            rest = &rest[end + 1..];
+        }
+        path.push_str(rest);
+
+        Ok(path)
+    }
+
+    /// Key of the method rate limit bucket for the given host. Riot tracks method rate limits per
+    /// platform or region.
+    pub fn rate_limit_key<T: WithHost>(&self, region: &T) -> String {
+        format!("{}:{}", region.name(), self.method_id)
+    }
+
+    /// Whether the route accepts the given query parameter.
+    pub fn accepts_query_param(&self, name: &str) -> bool {
+        self.query_params.contains(&name)
+    }
+}
+
+// champion-v3
+
+pub static CHAMPION_V3_GET_CHAMPION_INFO: Route = Route {
+    method_id: "champion-v3.getChampionInfo",
+    method: Method::GET,
+    path: "/lol/platform/v3/champion-rotations",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(3600),
+    auth: Auth::ApiKey,
+};
+
+// champion-mastery-v4
+
+pub static CHAMPION_MASTERY_V4_GET_ALL_CHAMPION_MASTERIES: Route = Route {
+    method_id: "champion-mastery-v4.getAllChampionMasteries",
+    method: Method::GET,
+    path: "/lol/champion-mastery/v4/champion-masteries/by-summoner/{encryptedSummonerId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY: Route = Route {
+    method_id: "champion-mastery-v4.getChampionMastery",
+    method: Method::GET,
+    path: "/lol/champion-mastery/v4/champion-masteries/by-summoner/{encryptedSummonerId}/by-champion/{championId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_SCORE: Route = Route {
+    method_id: "champion-mastery-v4.getChampionMasteryScore",
+    method: Method::GET,
+    path: "/lol/champion-mastery/v4/scores/by-summoner/{encryptedSummonerId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static CHAMPION_MASTERY_V4_GET_TOP_CHAMPION_MASTERIES: Route = Route {
+    method_id: "champion-mastery-v4.getTopChampionMasteries",
+    method: Method::GET,
+    path: "/lol/champion-mastery/v4/champion-masteries/by-summoner/{encryptedSummonerId}/top",
+    routing: Routing::Platform,
+    query_params: &["count"],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static CHAMPION_MASTERY_V4_GET_ALL_CHAMPION_MASTERIES_BY_PUUID: Route = Route {
+    method_id: "champion-mastery-v4.getAllChampionMasteriesByPUUID",
+    method: Method::GET,
+    path: "/lol/champion-mastery/v4/champion-masteries/by-puuid/{encryptedPUUID}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_BY_PUUID: Route = Route {
+    method_id: "champion-mastery-v4.getChampionMasteryByPUUID",
+    method: Method::GET,
+    path: "/lol/champion-mastery/v4/champion-masteries/by-puuid/{encryptedPUUID}/by-champion/{championId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static CHAMPION_MASTERY_V4_GET_TOP_CHAMPION_MASTERIES_BY_PUUID: Route = Route {
+    method_id: "champion-mastery-v4.getTopChampionMasteriesByPUUID",
+    method: Method::GET,
+    path: "/lol/champion-mastery/v4/champion-masteries/by-puuid/{encryptedPUUID}/top",
+    routing: Routing::Platform,
+    query_params: &["count"],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_SCORE_BY_PUUID: Route = Route {
+    method_id: "champion-mastery-v4.getChampionMasteryScoreByPUUID",
+    method: Method::GET,
+    path: "/lol/champion-mastery/v4/scores/by-puuid/{encryptedPUUID}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+// league-v4
+
+pub static LEAGUE_V4_GET_CHALLENGER_LEAGUE: Route = Route {
+    method_id: "league-v4.getChallengerLeague",
+    method: Method::GET,
+    path: "/lol/league/v4/challengerleagues/by-queue/{queue}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static LEAGUE_V4_GET_GRANDMASTER_LEAGUE: Route = Route {
+    method_id: "league-v4.getGrandmasterLeague",
+    method: Method::GET,
+    path: "/lol/league/v4/grandmasterleagues/by-queue/{queue}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static LEAGUE_V4_GET_MASTER_LEAGUE: Route = Route {
+    method_id: "league-v4.getMasterLeague",
+    method: Method::GET,
+    path: "/lol/league/v4/masterleagues/by-queue/{queue}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static LEAGUE_V4_GET_LEAGUE_BY_ID: Route = Route {
+    method_id: "league-v4.getLeagueById",
+    method: Method::GET,
+    path: "/lol/league/v4/leagues/{leagueId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static LEAGUE_V4_GET_LEAGUE_ENTRIES_FOR_SUMMONER: Route = Route {
+    method_id: "league-v4.getLeagueEntriesForSummoner",
+    method: Method::GET,
+    path: "/lol/league/v4/entries/by-summoner/{encryptedSummonerId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(60),
+    auth: Auth::ApiKey,
+};
+
+pub static LEAGUE_V4_GET_LEAGUE_ENTRIES: Route = Route {
+    method_id: "league-v4.getLeagueEntries",
+    method: Method::GET,
+    path: "/lol/league/v4/entries/{queue}/{tier}/{division}",
+    routing: Routing::Platform,
+    query_params: &["page"],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+// match-v4
+
+pub static MATCH_V4_GET_MATCH_IDS_BY_TOURNAMENT_CODE: Route = Route {
+    method_id: "match-v4.getMatchIdsByTournamentCode",
+    method: Method::GET,
+    path: "/lol/match/v4/matches/by-tournament-code/{tournamentCode}/ids",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(60),
+    auth: Auth::ApiKey,
+};
+
+pub static MATCH_V4_GET_MATCH: Route = Route {
+    method_id: "match-v4.getMatch",
+    method: Method::GET,
+    path: "/lol/match/v4/matches/{matchId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Immutable,
+    auth: Auth::ApiKey,
+};
+
+pub static MATCH_V4_GET_MATCH_BY_TOURNAMENT_CODE: Route = Route {
+    method_id: "match-v4.getMatchByTournamentCode",
+    method: Method::GET,
+    path: "/lol/match/v4/matches/{matchId}/by-tournament-code/{tournamentCode}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Immutable,
+    auth: Auth::ApiKey,
+};
+
+pub static MATCH_V4_GET_MATCHLIST: Route = Route {
+    method_id: "match-v4.getMatchlist",
+    method: Method::GET,
+    path: "/lol/match/v4/matchlists/by-account/{encryptedAccountId}",
+    routing: Routing::Platform,
+    query_params: &[
+        "champion",
+        "queue",
+        "endTime",
+        "beginTime",
+        "endIndex",
+        "beginIndex",
+    ],
+    cache: CachePolicy::Ttl(60),
+    auth: Auth::ApiKey,
+};
+
+pub static MATCH_V4_GET_MATCH_TIMELINE: Route = Route {
+    method_id: "match-v4.getMatchTimeline",
+    method: Method::GET,
+    path: "/lol/match/v4/timelines/by-match/{matchId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Immutable,
+    auth: Auth::ApiKey,
+};
+
+// summoner-v4
+
+pub static SUMMONER_V4_GET_BY_ACCOUNT_ID: Route = Route {
+    method_id: "summoner-v4.getByAccountId",
+    method: Method::GET,
+    path: "/lol/summoner/v4/summoners/by-account/{encryptedAccountId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static SUMMONER_V4_GET_BY_SUMMONER_NAME: Route = Route {
+    method_id: "summoner-v4.getBySummonerName",
+    method: Method::GET,
+    path: "/lol/summoner/v4/summoners/by-name/{summonerName}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static SUMMONER_V4_GET_BY_PUUID: Route = Route {
+    method_id: "summoner-v4.getByPUUID",
+    method: Method::GET,
+    path: "/lol/summoner/v4/summoners/by-puuid/{encryptedPUUID}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static SUMMONER_V4_GET_BY_SUMMONER_ID: Route = Route {
+    method_id: "summoner-v4.getBySummonerId",
+    method: Method::GET,
+    path: "/lol/summoner/v4/summoners/{encryptedSummonerId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static SUMMONER_V4_GET_BY_ACCESS_TOKEN: Route = Route {
+    method_id: "summoner-v4.getByAccessToken",
+    method: Method::GET,
+    path: "/lol/summoner/v4/summoners/me",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::Rso,
+};
+
+// spectator-v4
+
+pub static SPECTATOR_V4_GET_CURRENT_GAME_INFO_BY_SUMMONER: Route = Route {
+    method_id: "spectator-v4.getCurrentGameInfoBySummoner",
+    method: Method::GET,
+    path: "/lol/spectator/v4/active-games/by-summoner/{encryptedSummonerId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static SPECTATOR_V4_GET_FEATURED_GAMES: Route = Route {
+    method_id: "spectator-v4.getFeaturedGames",
+    method: Method::GET,
+    path: "/lol/spectator/v4/featured-games",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(120),
+    auth: Auth::ApiKey,
+};
+
+// lol-status-v4
+
+pub static LOL_STATUS_V4_GET_PLATFORM_DATA: Route = Route {
+    method_id: "lol-status-v4.getPlatformData",
+    method: Method::GET,
+    path: "/lol/status/v4/platform-data",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(60),
+    auth: Auth::ApiKey,
+};
+
+// clash-v1
+
+pub static CLASH_V1_GET_PLAYERS_BY_SUMMONER: Route = Route {
+    method_id: "clash-v1.getPlayersBySummoner",
+    method: Method::GET,
+    path: "/lol/clash/v1/players/by-summoner/{summonerId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(60),
+    auth: Auth::ApiKey,
+};
+
+pub static CLASH_V1_GET_TEAM_BY_ID: Route = Route {
+    method_id: "clash-v1.getTeamById",
+    method: Method::GET,
+    path: "/lol/clash/v1/teams/{teamId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(60),
+    auth: Auth::ApiKey,
+};
+
+pub static CLASH_V1_GET_TOURNAMENTS: Route = Route {
+    method_id: "clash-v1.getTournaments",
+    method: Method::GET,
+    path: "/lol/clash/v1/tournaments",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static CLASH_V1_GET_TOURNAMENT_BY_TEAM: Route = Route {
+    method_id: "clash-v1.getTournamentByTeam",
+    method: Method::GET,
+    path: "/lol/clash/v1/tournaments/by-team/{teamId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static CLASH_V1_GET_TOURNAMENT_BY_ID: Route = Route {
+    method_id: "clash-v1.getTournamentById",
+    method: Method::GET,
+    path: "/lol/clash/v1/tournaments/{tournamentId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+// tournament-v4
+
+pub static TOURNAMENT_V4_CREATE_TOURNAMENT_CODE: Route = Route {
+    method_id: "tournament-v4.createTournamentCode",
+    method: Method::POST,
+    path: "/lol/tournament/v4/codes",
+    routing: Routing::Regional,
+    query_params: &["count", "tournamentId"],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static TOURNAMENT_V4_GET_TOURNAMENT_CODE: Route = Route {
+    method_id: "tournament-v4.getTournamentCode",
+    method: Method::GET,
+    path: "/lol/tournament/v4/codes/{tournamentCode}",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static TOURNAMENT_V4_UPDATE_CODE: Route = Route {
+    method_id: "tournament-v4.updateCode",
+    method: Method::PUT,
+    path: "/lol/tournament/v4/codes/{tournamentCode}",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static TOURNAMENT_V4_GET_LOBBY_EVENTS_BY_CODE: Route = Route {
+    method_id: "tournament-v4.getLobbyEventsByCode",
+    method: Method::GET,
+    path: "/lol/tournament/v4/lobby-events/by-code/{tournamentCode}",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static TOURNAMENT_V4_REGISTER_PROVIDER_DATA: Route = Route {
+    method_id: "tournament-v4.registerProviderData",
+    method: Method::POST,
+    path: "/lol/tournament/v4/providers",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static TOURNAMENT_V4_REGISTER_TOURNAMENT: Route = Route {
+    method_id: "tournament-v4.registerTournament",
+    method: Method::POST,
+    path: "/lol/tournament/v4/tournaments",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+// tournament-stub-v4
+
+pub static TOURNAMENT_STUB_V4_CREATE_TOURNAMENT_CODE: Route = Route {
+    method_id: "tournament-stub-v4.createTournamentCode",
+    method: Method::POST,
+    path: "/lol/tournament-stub/v4/codes",
+    routing: Routing::Regional,
+    query_params: &["count", "tournamentId"],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static TOURNAMENT_STUB_V4_GET_LOBBY_EVENTS_BY_CODE: Route = Route {
+    method_id: "tournament-stub-v4.getLobbyEventsByCode",
+    method: Method::GET,
+    path: "/lol/tournament-stub/v4/lobby-events/by-code/{tournamentCode}",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static TOURNAMENT_STUB_V4_REGISTER_PROVIDER_DATA: Route = Route {
+    method_id: "tournament-stub-v4.registerProviderData",
+    method: Method::POST,
+    path: "/lol/tournament-stub/v4/providers",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static TOURNAMENT_STUB_V4_REGISTER_TOURNAMENT: Route = Route {
+    method_id: "tournament-stub-v4.registerTournament",
+    method: Method::POST,
+    path: "/lol/tournament-stub/v4/tournaments",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+// account-v1
+
+pub static ACCOUNT_V1_GET_BY_PUUID: Route = Route {
+    method_id: "account-v1.getByPuuid",
+    method: Method::GET,
+    path: "/riot/account/v1/accounts/by-puuid/{puuid}",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static ACCOUNT_V1_GET_BY_RIOT_ID: Route = Route {
+    method_id: "account-v1.getByRiotId",
+    method: Method::GET,
+    path: "/riot/account/v1/accounts/by-riot-id/{gameName}/{tagLine}",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static ACCOUNT_V1_GET_ACTIVE_SHARD: Route = Route {
+    method_id: "account-v1.getActiveShard",
+    method: Method::GET,
+    path: "/riot/account/v1/active-shards/by-game/{game}/by-puuid/{puuid}",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static ACCOUNT_V1_GET_BY_ACCESS_TOKEN: Route = Route {
+    method_id: "account-v1.getByAccessToken",
+    method: Method::GET,
+    path: "/riot/account/v1/accounts/me",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::Rso,
+};
+
+// match-v5
+
+pub static MATCH_V5_GET_MATCH_IDS_BY_PUUID: Route = Route {
+    method_id: "match-v5.getMatchIdsByPUUID",
+    method: Method::GET,
+    path: "/lol/match/v5/matches/by-puuid/{puuid}/ids",
+    routing: Routing::Regional,
+    query_params: &["startTime", "endTime", "queue", "type", "start", "count"],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static MATCH_V5_GET_MATCH: Route = Route {
+    method_id: "match-v5.getMatch",
+    method: Method::GET,
+    path: "/lol/match/v5/matches/{matchId}",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::Immutable,
+    auth: Auth::ApiKey,
+};
+
+pub static MATCH_V5_GET_TIMELINE: Route = Route {
+    method_id: "match-v5.getTimeline",
+    method: Method::GET,
+    path: "/lol/match/v5/matches/{matchId}/timeline",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::Immutable,
+    auth: Auth::ApiKey,
+};
+
+// league-exp-v4
+
+pub static LEAGUE_EXP_V4_GET_LEAGUE_ENTRIES: Route = Route {
+    method_id: "league-exp-v4.getLeagueEntries",
+    method: Method::GET,
+    path: "/lol/league-exp/v4/entries/{queue}/{tier}/{division}",
+    routing: Routing::Platform,
+    query_params: &["page"],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+// third-party-code-v4
+
+pub static THIRD_PARTY_CODE_V4_GET_THIRD_PARTY_CODE_BY_SUMMONER_ID: Route = Route {
+    method_id: "third-party-code-v4.getThirdPartyCodeBySummonerId",
+    method: Method::GET,
+    path: "/lol/platform/v4/third-party-code/by-summoner/{encryptedSummonerId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+// challenges-v1
+
+pub static CHALLENGES_V1_GET_ALL_CHALLENGE_CONFIGS: Route = Route {
+    method_id: "challenges-v1.getAllChallengeConfigs",
+    method: Method::GET,
+    path: "/lol/challenges/v1/challenges/config",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(3600),
+    auth: Auth::ApiKey,
+};
+
+pub static CHALLENGES_V1_GET_ALL_CHALLENGE_PERCENTILES: Route = Route {
+    method_id: "challenges-v1.getAllChallengePercentiles",
+    method: Method::GET,
+    path: "/lol/challenges/v1/challenges/percentiles",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(3600),
+    auth: Auth::ApiKey,
+};
+
+pub static CHALLENGES_V1_GET_CHALLENGE_CONFIGS: Route = Route {
+    method_id: "challenges-v1.getChallengeConfigs",
+    method: Method::GET,
+    path: "/lol/challenges/v1/challenges/{challengeId}/config",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(3600),
+    auth: Auth::ApiKey,
+};
+
+pub static CHALLENGES_V1_GET_CHALLENGE_LEADERBOARDS: Route = Route {
+    method_id: "challenges-v1.getChallengeLeaderboards",
+    method: Method::GET,
+    path: "/lol/challenges/v1/challenges/{challengeId}/leaderboards/by-level/{level}",
+    routing: Routing::Platform,
+    query_params: &["limit"],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static CHALLENGES_V1_GET_CHALLENGE_PERCENTILES: Route = Route {
+    method_id: "challenges-v1.getChallengePercentiles",
+    method: Method::GET,
+    path: "/lol/challenges/v1/challenges/{challengeId}/percentiles",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(3600),
+    auth: Auth::ApiKey,
+};
+
+pub static CHALLENGES_V1_GET_PLAYER_DATA: Route = Route {
+    method_id: "challenges-v1.getPlayerData",
+    method: Method::GET,
+    path: "/lol/challenges/v1/player-data/{puuid}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+// tft-summoner-v1
+
+pub static TFT_SUMMONER_V1_GET_BY_ACCOUNT_ID: Route = Route {
+    method_id: "tft-summoner-v1.getByAccountId",
+    method: Method::GET,
+    path: "/tft/summoner/v1/summoners/by-account/{encryptedAccountId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static TFT_SUMMONER_V1_GET_BY_SUMMONER_NAME: Route = Route {
+    method_id: "tft-summoner-v1.getBySummonerName",
+    method: Method::GET,
+    path: "/tft/summoner/v1/summoners/by-name/{summonerName}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static TFT_SUMMONER_V1_GET_BY_PUUID: Route = Route {
+    method_id: "tft-summoner-v1.getByPUUID",
+    method: Method::GET,
+    path: "/tft/summoner/v1/summoners/by-puuid/{encryptedPUUID}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+pub static TFT_SUMMONER_V1_GET_BY_SUMMONER_ID: Route = Route {
+    method_id: "tft-summoner-v1.getBySummonerId",
+    method: Method::GET,
+    path: "/tft/summoner/v1/summoners/{encryptedSummonerId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(600),
+    auth: Auth::ApiKey,
+};
+
+// tft-league-v1
+
+pub static TFT_LEAGUE_V1_GET_CHALLENGER_LEAGUE: Route = Route {
+    method_id: "tft-league-v1.getChallengerLeague",
+    method: Method::GET,
+    path: "/tft/league/v1/challenger",
+    routing: Routing::Platform,
+    query_params: &["queue"],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static TFT_LEAGUE_V1_GET_GRANDMASTER_LEAGUE: Route = Route {
+    method_id: "tft-league-v1.getGrandmasterLeague",
+    method: Method::GET,
+    path: "/tft/league/v1/grandmaster",
+    routing: Routing::Platform,
+    query_params: &["queue"],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static TFT_LEAGUE_V1_GET_MASTER_LEAGUE: Route = Route {
+    method_id: "tft-league-v1.getMasterLeague",
+    method: Method::GET,
+    path: "/tft/league/v1/master",
+    routing: Routing::Platform,
+    query_params: &["queue"],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static TFT_LEAGUE_V1_GET_LEAGUE_BY_ID: Route = Route {
+    method_id: "tft-league-v1.getLeagueById",
+    method: Method::GET,
+    path: "/tft/league/v1/leagues/{leagueId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static TFT_LEAGUE_V1_GET_LEAGUE_ENTRIES_FOR_SUMMONER: Route = Route {
+    method_id: "tft-league-v1.getLeagueEntriesForSummoner",
+    method: Method::GET,
+    path: "/tft/league/v1/entries/by-summoner/{summonerId}",
+    routing: Routing::Platform,
+    query_params: &[],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+pub static TFT_LEAGUE_V1_GET_LEAGUE_ENTRIES: Route = Route {
+    method_id: "tft-league-v1.getLeagueEntries",
+    method: Method::GET,
+    path: "/tft/league/v1/entries/{tier}/{division}",
+    routing: Routing::Platform,
+    query_params: &["queue", "page"],
+    cache: CachePolicy::Ttl(300),
+    auth: Auth::ApiKey,
+};
+
+// tft-match-v1
+
+pub static TFT_MATCH_V1_GET_MATCH_IDS_BY_PUUID: Route = Route {
+    method_id: "tft-match-v1.getMatchIdsByPUUID",
+    method: Method::GET,
+    path: "/tft/match/v1/matches/by-puuid/{puuid}/ids",
+    routing: Routing::Regional,
+    query_params: &["start", "endTime", "startTime", "count"],
+    cache: CachePolicy::NoCache,
+    auth: Auth::ApiKey,
+};
+
+pub static TFT_MATCH_V1_GET_MATCH: Route = Route {
+    method_id: "tft-match-v1.getMatch",
+    method: Method::GET,
+    path: "/tft/match/v1/matches/{matchId}",
+    routing: Routing::Regional,
+    query_params: &[],
+    cache: CachePolicy::Immutable,
+    auth: Auth::ApiKey,
+};
+
+/// Every route known to the wrapper.
+pub static ROUTES: &[&Route] = &[
+    &CHAMPION_V3_GET_CHAMPION_INFO,
+    &CHAMPION_MASTERY_V4_GET_ALL_CHAMPION_MASTERIES,
+    &CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY,
+    &CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_SCORE,
+    &CHAMPION_MASTERY_V4_GET_TOP_CHAMPION_MASTERIES,
+    &CHAMPION_MASTERY_V4_GET_ALL_CHAMPION_MASTERIES_BY_PUUID,
+    &CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_BY_PUUID,
+    &CHAMPION_MASTERY_V4_GET_TOP_CHAMPION_MASTERIES_BY_PUUID,
+    &CHAMPION_MASTERY_V4_GET_CHAMPION_MASTERY_SCORE_BY_PUUID,
+    &LEAGUE_V4_GET_CHALLENGER_LEAGUE,
+    &LEAGUE_V4_GET_GRANDMASTER_LEAGUE,
+    &LEAGUE_V4_GET_MASTER_LEAGUE,
+    &LEAGUE_V4_GET_LEAGUE_BY_ID,
+    &LEAGUE_V4_GET_LEAGUE_ENTRIES_FOR_SUMMONER,
+    &LEAGUE_V4_GET_LEAGUE_ENTRIES,
+    &MATCH_V4_GET_MATCH_IDS_BY_TOURNAMENT_CODE,
+    &MATCH_V4_GET_MATCH,
+    &MATCH_V4_GET_MATCH_BY_TOURNAMENT_CODE,
+    &MATCH_V4_GET_MATCHLIST,
+    &MATCH_V4_GET_MATCH_TIMELINE,
+    &SUMMONER_V4_GET_BY_ACCOUNT_ID,
+    &SUMMONER_V4_GET_BY_SUMMONER_NAME,
+    &SUMMONER_V4_GET_BY_PUUID,
+    &SUMMONER_V4_GET_BY_SUMMONER_ID,
+    &SUMMONER_V4_GET_BY_ACCESS_TOKEN,
+    &SPECTATOR_V4_GET_CURRENT_GAME_INFO_BY_SUMMONER,
+    &SPECTATOR_V4_GET_FEATURED_GAMES,
+    &LOL_STATUS_V4_GET_PLATFORM_DATA,
+    &CLASH_V1_GET_PLAYERS_BY_SUMMONER,
+    &CLASH_V1_GET_TEAM_BY_ID,
+    &CLASH_V1_GET_TOURNAMENTS,
+    &CLASH_V1_GET_TOURNAMENT_BY_TEAM,
+    &CLASH_V1_GET_TOURNAMENT_BY_ID,
+    &TOURNAMENT_V4_CREATE_TOURNAMENT_CODE,
+    &TOURNAMENT_V4_GET_TOURNAMENT_CODE,
+    &TOURNAMENT_V4_UPDATE_CODE,
+    &TOURNAMENT_V4_GET_LOBBY_EVENTS_BY_CODE,
+    &TOURNAMENT_V4_REGISTER_PROVIDER_DATA,
+    &TOURNAMENT_V4_REGISTER_TOURNAMENT,
+    &TOURNAMENT_STUB_V4_CREATE_TOURNAMENT_CODE,
+    &TOURNAMENT_STUB_V4_GET_LOBBY_EVENTS_BY_CODE,
+    &TOURNAMENT_STUB_V4_REGISTER_PROVIDER_DATA,
+    &TOURNAMENT_STUB_V4_REGISTER_TOURNAMENT,
+    &ACCOUNT_V1_GET_BY_PUUID,
+    &ACCOUNT_V1_GET_BY_RIOT_ID,
+    &ACCOUNT_V1_GET_ACTIVE_SHARD,
+    &ACCOUNT_V1_GET_BY_ACCESS_TOKEN,
+    &MATCH_V5_GET_MATCH_IDS_BY_PUUID,
+    &MATCH_V5_GET_MATCH,
+    &MATCH_V5_GET_TIMELINE,
+    &LEAGUE_EXP_V4_GET_LEAGUE_ENTRIES,
+    &THIRD_PARTY_CODE_V4_GET_THIRD_PARTY_CODE_BY_SUMMONER_ID,
+    &CHALLENGES_V1_GET_ALL_CHALLENGE_CONFIGS,
+    &CHALLENGES_V1_GET_ALL_CHALLENGE_PERCENTILES,
+    &CHALLENGES_V1_GET_CHALLENGE_CONFIGS,
+    &CHALLENGES_V1_GET_CHALLENGE_LEADERBOARDS,
+    &CHALLENGES_V1_GET_CHALLENGE_PERCENTILES,
+    &CHALLENGES_V1_GET_PLAYER_DATA,
+    &TFT_SUMMONER_V1_GET_BY_ACCOUNT_ID,
+    &TFT_SUMMONER_V1_GET_BY_SUMMONER_NAME,
+    &TFT_SUMMONER_V1_GET_BY_PUUID,
+    &TFT_SUMMONER_V1_GET_BY_SUMMONER_ID,
+    &TFT_LEAGUE_V1_GET_CHALLENGER_LEAGUE,
+    &TFT_LEAGUE_V1_GET_GRANDMASTER_LEAGUE,
+    &TFT_LEAGUE_V1_GET_MASTER_LEAGUE,
+    &TFT_LEAGUE_V1_GET_LEAGUE_BY_ID,
+    &TFT_LEAGUE_V1_GET_LEAGUE_ENTRIES_FOR_SUMMONER,
+    &TFT_LEAGUE_V1_GET_LEAGUE_ENTRIES,
+    &TFT_MATCH_V1_GET_MATCH_IDS_BY_PUUID,
+    &TFT_MATCH_V1_GET_MATCH,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::Platforms;
+    use std::collections::HashSet;
+
+    /// Expected path for every route, built with the placeholders replaced by `arg0`, `arg1`...
+    const GOLDEN_PATHS: &[(&str, &str)] = &[
+        (
+            "champion-v3.getChampionInfo",
+            "/lol/platform/v3/champion-rotations",
+        ),
+        (
+            "champion-mastery-v4.getAllChampionMasteries",
+            "/lol/champion-mastery/v4/champion-masteries/by-summoner/arg0",
+        ),
+        (
+            "champion-mastery-v4.getChampionMastery",
+            "/lol/champion-mastery/v4/champion-masteries/by-summoner/arg0/by-champion/arg1",
+        ),
+        (
+            "champion-mastery-v4.getChampionMasteryScore",
+            "/lol/champion-mastery/v4/scores/by-summoner/arg0",
+        ),
+        (
+            "champion-mastery-v4.getTopChampionMasteries",
+            "/lol/champion-mastery/v4/champion-masteries/by-summoner/arg0/top",
+        ),
+        (
+            "champion-mastery-v4.getAllChampionMasteriesByPUUID",
+            "/lol/champion-mastery/v4/champion-masteries/by-puuid/arg0",
+        ),
+        (
+            "champion-mastery-v4.getChampionMasteryByPUUID",
+            "/lol/champion-mastery/v4/champion-masteries/by-puuid/arg0/by-champion/arg1",
+        ),
+        (
+            "champion-mastery-v4.getTopChampionMasteriesByPUUID",
+            "/lol/champion-mastery/v4/champion-masteries/by-puuid/arg0/top",
+        ),
+        (
+            "champion-mastery-v4.getChampionMasteryScoreByPUUID",
+            "/lol/champion-mastery/v4/scores/by-puuid/arg0",
+        ),
+        (
+            "league-v4.getChallengerLeague",
+            "/lol/league/v4/challengerleagues/by-queue/arg0",
+        ),
+        (
+            "league-v4.getGrandmasterLeague",
+            "/lol/league/v4/grandmasterleagues/by-queue/arg0",
+        ),
+        (
+            "league-v4.getMasterLeague",
+            "/lol/league/v4/masterleagues/by-queue/arg0",
+        ),
+        ("league-v4.getLeagueById", "/lol/league/v4/leagues/arg0"),
+        (
+            "league-v4.getLeagueEntriesForSummoner",
+            "/lol/league/v4/entries/by-summoner/arg0",
+        ),
+        (
+            "league-v4.getLeagueEntries",
+            "/lol/league/v4/entries/arg0/arg1/arg2",
+        ),
+        (
+            "match-v4.getMatchIdsByTournamentCode",
+            "/lol/match/v4/matches/by-tournament-code/arg0/ids",
+        ),
+        ("match-v4.getMatch", "/lol/match/v4/matches/arg0"),
+        (
+            "match-v4.getMatchByTournamentCode",
+            "/lol/match/v4/matches/arg0/by-tournament-code/arg1",
+        ),
+        (
+            "match-v4.getMatchlist",
+            "/lol/match/v4/matchlists/by-account/arg0",
+        ),
+        (
+            "match-v4.getMatchTimeline",
+            "/lol/match/v4/timelines/by-match/arg0",
+        ),
+        (
+            "summoner-v4.getByAccountId",
+            "/lol/summoner/v4/summoners/by-account/arg0",
+        ),
+        (
+            "summoner-v4.getBySummonerName",
+            "/lol/summoner/v4/summoners/by-name/arg0",
+        ),
+        (
+            "summoner-v4.getByPUUID",
+            "/lol/summoner/v4/summoners/by-puuid/arg0",
+        ),
+        (
+            "summoner-v4.getBySummonerId",
+            "/lol/summoner/v4/summoners/arg0",
+        ),
+        (
+            "summoner-v4.getByAccessToken",
+            "/lol/summoner/v4/summoners/me",
+        ),
+        (
+            "spectator-v4.getCurrentGameInfoBySummoner",
+            "/lol/spectator/v4/active-games/by-summoner/arg0",
+        ),
+        (
+            "spectator-v4.getFeaturedGames",
+            "/lol/spectator/v4/featured-games",
+        ),
+        (
+            "lol-status-v4.getPlatformData",
+            "/lol/status/v4/platform-data",
+        ),
+        (
+            "clash-v1.getPlayersBySummoner",
+            "/lol/clash/v1/players/by-summoner/arg0",
+        ),
+        ("clash-v1.getTeamById", "/lol/clash/v1/teams/arg0"),
+        ("clash-v1.getTournaments", "/lol/clash/v1/tournaments"),
+        (
+            "clash-v1.getTournamentByTeam",
+            "/lol/clash/v1/tournaments/by-team/arg0",
+        ),
+        (
+            "clash-v1.getTournamentById",
+            "/lol/clash/v1/tournaments/arg0",
+        ),
+        (
+            "tournament-v4.createTournamentCode",
+            "/lol/tournament/v4/codes",
+        ),
+        (
+            "tournament-v4.getTournamentCode",
+            "/lol/tournament/v4/codes/arg0",
+        ),
+        ("tournament-v4.updateCode", "/lol/tournament/v4/codes/arg0"),
+        (
+            "tournament-v4.getLobbyEventsByCode",
+            "/lol/tournament/v4/lobby-events/by-code/arg0",
+        ),
+        (
+            "tournament-v4.registerProviderData",
+            "/lol/tournament/v4/providers",
+        ),
+        (
+            "tournament-v4.registerTournament",
+            "/lol/tournament/v4/tournaments",
+        ),
+        (
+            "tournament-stub-v4.createTournamentCode",
+            "/lol/tournament-stub/v4/codes",
+        ),
+        (
+            "tournament-stub-v4.getLobbyEventsByCode",
+            "/lol/tournament-stub/v4/lobby-events/by-code/arg0",
+        ),
+        (
+            "tournament-stub-v4.registerProviderData",
+            "/lol/tournament-stub/v4/providers",
+        ),
+        (
+            "tournament-stub-v4.registerTournament",
+            "/lol/tournament-stub/v4/tournaments",
+        ),
+        (
+            "account-v1.getByPuuid",
+            "/riot/account/v1/accounts/by-puuid/arg0",
+        ),
+        (
+            "account-v1.getByRiotId",
+            "/riot/account/v1/accounts/by-riot-id/arg0/arg1",
+        ),
+        (
+            "account-v1.getActiveShard",
+            "/riot/account/v1/active-shards/by-game/arg0/by-puuid/arg1",
+        ),
+        (
+            "account-v1.getByAccessToken",
+            "/riot/account/v1/accounts/me",
+        ),
+        (
+            "match-v5.getMatchIdsByPUUID",
+            "/lol/match/v5/matches/by-puuid/arg0/ids",
+        ),
+        ("match-v5.getMatch", "/lol/match/v5/matches/arg0"),
+        (
+            "match-v5.getTimeline",
+            "/lol/match/v5/matches/arg0/timeline",
+        ),
+        (
+            "league-exp-v4.getLeagueEntries",
+            "/lol/league-exp/v4/entries/arg0/arg1/arg2",
+        ),
+        (
+            "third-party-code-v4.getThirdPartyCodeBySummonerId",
+            "/lol/platform/v4/third-party-code/by-summoner/arg0",
+        ),
+        (
+            "challenges-v1.getAllChallengeConfigs",
+            "/lol/challenges/v1/challenges/config",
+        ),
+        (
+            "challenges-v1.getAllChallengePercentiles",
+            "/lol/challenges/v1/challenges/percentiles",
+        ),
+        (
+            "challenges-v1.getChallengeConfigs",
+            "/lol/challenges/v1/challenges/arg0/config",
+        ),
+        (
+            "challenges-v1.getChallengeLeaderboards",
+            "/lol/challenges/v1/challenges/arg0/leaderboards/by-level/arg1",
+        ),
+        (
+            "challenges-v1.getChallengePercentiles",
+            "/lol/challenges/v1/challenges/arg0/percentiles",
+        ),
+        (
+            "challenges-v1.getPlayerData",
+            "/lol/challenges/v1/player-data/arg0",
+        ),
+        (
+            "tft-summoner-v1.getByAccountId",
+            "/tft/summoner/v1/summoners/by-account/arg0",
+        ),
+        (
+            "tft-summoner-v1.getBySummonerName",
+            "/tft/summoner/v1/summoners/by-name/arg0",
+        ),
+        (
+            "tft-summoner-v1.getByPUUID",
+            "/tft/summoner/v1/summoners/by-puuid/arg0",
+        ),
+        (
+            "tft-summoner-v1.getBySummonerId",
+            "/tft/summoner/v1/summoners/arg0",
+        ),
+        (
+            "tft-league-v1.getChallengerLeague",
+            "/tft/league/v1/challenger",
+        ),
+        (
+            "tft-league-v1.getGrandmasterLeague",
+            "/tft/league/v1/grandmaster",
+        ),
+        ("tft-league-v1.getMasterLeague", "/tft/league/v1/master"),
+        ("tft-league-v1.getLeagueById", "/tft/league/v1/leagues/arg0"),
+        (
+            "tft-league-v1.getLeagueEntriesForSummoner",
+            "/tft/league/v1/entries/by-summoner/arg0",
+        ),
+        (
+            "tft-league-v1.getLeagueEntries",
+            "/tft/league/v1/entries/arg0/arg1",
+        ),
+        (
+            "tft-match-v1.getMatchIdsByPUUID",
+            "/tft/match/v1/matches/by-puuid/arg0/ids",
+        ),
+        ("tft-match-v1.getMatch", "/tft/match/v1/matches/arg0"),
+    ];
+
+    fn placeholder_args(route: &Route) -> Vec<String> {
+        (0..route.path.matches('{').count())
+            .map(|i| format!("arg{}", i))
+            .collect()
+    }
+
+    #[test]
+    fn builds_golden_paths() {
+        assert_eq!(ROUTES.len(), GOLDEN_PATHS.len());
+
+        for (route, (method_id, expected)) in ROUTES.iter().zip(GOLDEN_PATHS) {
+            let args = placeholder_args(route);
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+            assert_eq!(route.method_id, *method_id);
+            assert_eq!(
+                route.build_path(&args).unwrap(),
+                *expected,
+                "{}",
+                route.method_id
+            );
+        }
+    }
+
+    #[test]
+    fn method_ids_are_unique() {
+        let ids: HashSet<_> = ROUTES.iter().map(|route| route.method_id).collect();
+        assert_eq!(ids.len(), ROUTES.len());
+    }
+
+    #[test]
+    fn paths_are_absolute() {
+        for route in ROUTES {
+            assert!(route.path.starts_with('/'), "{}", route.method_id);
+        }
+    }
+
+    #[test]
+    fn encodes_path_arguments() {
+        assert_eq!(
+            SUMMONER_V4_GET_BY_SUMMONER_NAME
+                .build_path(&["Hide on bush"])
+                .unwrap(),
+            "/lol/summoner/v4/summoners/by-name/Hide%20on%20bush"
+        );
+        assert_eq!(
+            LEAGUE_V4_GET_LEAGUE_BY_ID.build_path(&["a/b?c"]).unwrap(),
+            "/lol/league/v4/leagues/a%2Fb%3Fc"
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_path_arguments() {
+        for args in &[&[][..], &["a", "b"][..]] {
+            match LEAGUE_V4_GET_LEAGUE_BY_ID.build_path(args) {
+                Err(FetchError::InvalidRequest(message)) => {
+                    assert!(message.contains("league-v4.getLeagueById"))
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn builds_rate_limit_keys() {
+        assert_eq!(
+            SUMMONER_V4_GET_BY_PUUID.rate_limit_key(&Platforms::Euw1),
+            "euw1:summoner-v4.getByPUUID"
+        );
+    }
+
+    #[test]
+    fn exposes_cache_policies() {
+        assert_eq!(MATCH_V4_GET_MATCH.cache, CachePolicy::Immutable);
+        assert_eq!(
+            CHAMPION_V3_GET_CHAMPION_INFO.cache.ttl(),
+            Some(Duration::from_secs(3600))
+        );
+        assert_eq!(CachePolicy::NoCache.ttl(), None);
+        assert_eq!(CachePolicy::Immutable.ttl(), None);
+        assert!(CachePolicy::Immutable.is_immutable());
+        assert!(!CachePolicy::Ttl(60).is_immutable());
+    }
+    #[test]
+    fn marks_rso_routes() {
+        assert_eq!(SUMMONER_V4_GET_BY_SUMMONER_ID.auth, Auth::ApiKey);
+        assert_eq!(SUMMONER_V4_GET_BY_ACCESS_TOKEN.auth, Auth::Rso);
+        assert!(ROUTES
+            .iter()
+            .filter(|route| route.auth == Auth::Rso)
+            .all(|route| route.path.ends_with("/me")));
+    }
+}
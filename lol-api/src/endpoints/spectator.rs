@@ -0,0 +1,55 @@
+use failure::Error;
+use hyper::rt::Future;
+
+use crate::{
+    constants::WithHost,
+    models::{CurrentGameInfo, FeaturedGames},
+    RiotApi,
+};
+
+use super::routes::{
+    SPECTATOR_V4_GET_CURRENT_GAME_INFO_BY_SUMMONER, SPECTATOR_V4_GET_FEATURED_GAMES,
+};
+
+/// SpectatorV4 endpoints.
+///
+/// <a href="https://developer.riotgames.com/apis#spectator-v4">Official API Documentation</a>
+pub struct SpectatorV4<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> SpectatorV4<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get current game information for the given summoner ID. Resolves to `None` when the
+    /// summoner is not in a game.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#spectator-v4/GET_getCurrentGameInfoBySummoner">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_current_game_info_by_summoner<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_summoner_id: &str,
+    ) -> impl Future<Item = Option<CurrentGameInfo>, Error = Error> {
+        self.api.get_optional(
+            region,
+            &SPECTATOR_V4_GET_CURRENT_GAME_INFO_BY_SUMMONER,
+            &[encrypted_summoner_id],
+        )
+    }
+
+    /// Get list of featured games.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#spectator-v4/GET_getFeaturedGames">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_featured_games<T: WithHost>(
+        &self,
+        region: T,
+    ) -> impl Future<Item = FeaturedGames, Error = Error> {
+        self.api.get(region, &SPECTATOR_V4_GET_FEATURED_GAMES, &[])
+    }
+}
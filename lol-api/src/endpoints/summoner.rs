@@ -3,7 +3,10 @@ use hyper::rt::Future;
 
 use crate::{constants::WithHost, models::SummonerDTO, RiotApi};
 
-use super::SUMMONER_API_PATH;
+use super::routes::{
+    SUMMONER_V4_GET_BY_ACCESS_TOKEN, SUMMONER_V4_GET_BY_ACCOUNT_ID, SUMMONER_V4_GET_BY_PUUID,
+    SUMMONER_V4_GET_BY_SUMMONER_ID, SUMMONER_V4_GET_BY_SUMMONER_NAME,
+};
 
 /// SummonerV4 endpoints.
 ///
@@ -27,8 +30,8 @@ impl<'a> SummonerV4<'a> {
         region: T,
         summoner_name: &str,
     ) -> impl Future<Item = SummonerDTO, Error = Error> {
-        let path = format!("{}/by-name/{}", SUMMONER_API_PATH, summoner_name);
-        self.api.get(region, path)
+        self.api
+            .get(region, &SUMMONER_V4_GET_BY_SUMMONER_NAME, &[summoner_name])
     }
 
     /// Get a summoner by account ID.
@@ -41,8 +44,11 @@ impl<'a> SummonerV4<'a> {
         region: T,
         encrypted_account_id: &str,
     ) -> impl Future<Item = SummonerDTO, Error = Error> {
-        let path = format!("{}/by-account/{}", SUMMONER_API_PATH, encrypted_account_id);
-        self.api.get(region, path)
+        self.api.get(
+            region,
+            &SUMMONER_V4_GET_BY_ACCOUNT_ID,
+            &[encrypted_account_id],
+        )
     }
 
     /// Get a summoner by PUUID.
@@ -55,8 +61,8 @@ impl<'a> SummonerV4<'a> {
         region: T,
         encrypted_puuid: &str,
     ) -> impl Future<Item = SummonerDTO, Error = Error> {
-        let path = format!("{}/by-puuid/{}", SUMMONER_API_PATH, encrypted_puuid);
-        self.api.get(region, path)
+        self.api
+            .get(region, &SUMMONER_V4_GET_BY_PUUID, &[encrypted_puuid])
     }
 
     /// Get a summoner by summoner ID.
@@ -68,7 +74,34 @@ impl<'a> SummonerV4<'a> {
         region: T,
         encrypted_summoner_id: &str,
     ) -> impl Future<Item = SummonerDTO, Error = Error> {
-        let path = format!("{}/{}", SUMMONER_API_PATH, encrypted_summoner_id);
-        self.api.get(region, path)
+        self.api.get(
+            region,
+            &SUMMONER_V4_GET_BY_SUMMONER_ID,
+            &[encrypted_summoner_id],
+        )
+    }
+
+    /// Get the summoner signed in with Riot Sign-On, using the client's `TokenProvider`.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#summoner-v4/GET_getByAccessToken">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_me<T: WithHost>(&self, region: T) -> impl Future<Item = SummonerDTO, Error = Error> {
+        self.api
+            .get_with_rso(region, &SUMMONER_V4_GET_BY_ACCESS_TOKEN, &[])
+    }
+
+    /// Get the summoner the given RSO access token belongs to.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#summoner-v4/GET_getByAccessToken">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_me_with_token<T: WithHost>(
+        &self,
+        region: T,
+        access_token: &str,
+    ) -> impl Future<Item = SummonerDTO, Error = Error> {
+        self.api
+            .get_with_token(region, &SUMMONER_V4_GET_BY_ACCESS_TOKEN, &[], access_token)
     }
 }
@@ -0,0 +1,324 @@
+use chrono::serde::ts_seconds_option;
+use chrono::{DateTime, Utc};
+use failure::Error;
+use hyper::rt::Future;
+use serde_derive::Serialize;
+
+use crate::{
+    constants::{Division, Queue, Regions, Tier, WithHost},
+    models::{
+        tft::{LeagueEntryDTO, MatchDTO},
+        LeagueListDTO, SummonerDTO,
+    },
+    RiotApi,
+};
+
+use super::{
+    routes::{
+        TFT_LEAGUE_V1_GET_CHALLENGER_LEAGUE, TFT_LEAGUE_V1_GET_GRANDMASTER_LEAGUE,
+        TFT_LEAGUE_V1_GET_LEAGUE_BY_ID, TFT_LEAGUE_V1_GET_LEAGUE_ENTRIES,
+        TFT_LEAGUE_V1_GET_LEAGUE_ENTRIES_FOR_SUMMONER, TFT_LEAGUE_V1_GET_MASTER_LEAGUE,
+        TFT_MATCH_V1_GET_MATCH, TFT_MATCH_V1_GET_MATCH_IDS_BY_PUUID,
+        TFT_SUMMONER_V1_GET_BY_ACCOUNT_ID, TFT_SUMMONER_V1_GET_BY_PUUID,
+        TFT_SUMMONER_V1_GET_BY_SUMMONER_ID, TFT_SUMMONER_V1_GET_BY_SUMMONER_NAME,
+    },
+    to_query, GetLeagueEntriesParams,
+};
+
+/// TftSummonerV1 endpoints.
+///
+/// <a href="https://developer.riotgames.com/apis#tft-summoner-v1">Official API Documentation</a>
+pub struct TftSummonerV1<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> TftSummonerV1<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get a summoner by summoner name.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-summoner-v1/GET_getBySummonerName">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_by_summoner_name<T: WithHost>(
+        &self,
+        region: T,
+        summoner_name: &str,
+    ) -> impl Future<Item = SummonerDTO, Error = Error> {
+        self.api.get(
+            region,
+            &TFT_SUMMONER_V1_GET_BY_SUMMONER_NAME,
+            &[summoner_name],
+        )
+    }
+
+    /// Get a summoner by account ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-summoner-v1/GET_getByAccountId">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_by_account_id<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_account_id: &str,
+    ) -> impl Future<Item = SummonerDTO, Error = Error> {
+        self.api.get(
+            region,
+            &TFT_SUMMONER_V1_GET_BY_ACCOUNT_ID,
+            &[encrypted_account_id],
+        )
+    }
+
+    /// Get a summoner by PUUID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-summoner-v1/GET_getByPUUID">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_by_puuid<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_puuid: &str,
+    ) -> impl Future<Item = SummonerDTO, Error = Error> {
+        self.api
+            .get(region, &TFT_SUMMONER_V1_GET_BY_PUUID, &[encrypted_puuid])
+    }
+
+    /// Get a summoner by summoner ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-summoner-v1/GET_getBySummonerId">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_by_summoner_id<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_summoner_id: &str,
+    ) -> impl Future<Item = SummonerDTO, Error = Error> {
+        self.api.get(
+            region,
+            &TFT_SUMMONER_V1_GET_BY_SUMMONER_ID,
+            &[encrypted_summoner_id],
+        )
+    }
+}
+
+/// TftLeagueV1 endpoints.
+///
+/// <a href="https://developer.riotgames.com/apis#tft-league-v1">Official API Documentation</a>
+pub struct TftLeagueV1<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> TftLeagueV1<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get the challenger league for the given queue, usually `Queue::RankedTft`.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-league-v1/GET_getChallengerLeague">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_challenger_league<T: WithHost>(
+        &self,
+        region: T,
+        queue: Queue,
+    ) -> impl Future<Item = LeagueListDTO, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &TFT_LEAGUE_V1_GET_CHALLENGER_LEAGUE,
+            &[],
+            to_query(&QueueQuery::new(&queue, GetLeagueEntriesParams::default())),
+        )
+    }
+
+    /// Get the grandmaster league for the given queue.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-league-v1/GET_getGrandmasterLeague">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_grandmaster_league<T: WithHost>(
+        &self,
+        region: T,
+        queue: Queue,
+    ) -> impl Future<Item = LeagueListDTO, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &TFT_LEAGUE_V1_GET_GRANDMASTER_LEAGUE,
+            &[],
+            to_query(&QueueQuery::new(&queue, GetLeagueEntriesParams::default())),
+        )
+    }
+
+    /// Get the master league for the given queue.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-league-v1/GET_getMasterLeague">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_master_league<T: WithHost>(
+        &self,
+        region: T,
+        queue: Queue,
+    ) -> impl Future<Item = LeagueListDTO, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &TFT_LEAGUE_V1_GET_MASTER_LEAGUE,
+            &[],
+            to_query(&QueueQuery::new(&queue, GetLeagueEntriesParams::default())),
+        )
+    }
+
+    /// Get league with given ID, including inactive entries.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-league-v1/GET_getLeagueById">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_league_by_id<T: WithHost>(
+        &self,
+        region: T,
+        league_id: &str,
+    ) -> impl Future<Item = LeagueListDTO, Error = Error> {
+        self.api
+            .get(region, &TFT_LEAGUE_V1_GET_LEAGUE_BY_ID, &[league_id])
+    }
+
+    /// Get league entries in all TFT queues for a given summoner ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-league-v1/GET_getLeagueEntriesForSummoner">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_league_entries_for_summoner<T: WithHost>(
+        &self,
+        region: T,
+        summoner_id: &str,
+    ) -> impl Future<Item = Vec<LeagueEntryDTO>, Error = Error> {
+        self.api.get(
+            region,
+            &TFT_LEAGUE_V1_GET_LEAGUE_ENTRIES_FOR_SUMMONER,
+            &[summoner_id],
+        )
+    }
+
+    /// Get all the league entries of the given queue.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-league-v1/GET_getLeagueEntries">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_league_entries<T: WithHost>(
+        &self,
+        region: T,
+        queue: Queue,
+        tier: Tier,
+        division: Division,
+        parameters: GetLeagueEntriesParams,
+    ) -> impl Future<Item = Vec<LeagueEntryDTO>, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &TFT_LEAGUE_V1_GET_LEAGUE_ENTRIES,
+            &[tier.value(), division.value()],
+            to_query(&QueueQuery::new(&queue, parameters)),
+        )
+    }
+}
+
+/// TftMatchV1 endpoints. Served from the regional hosts, see `Regions`.
+///
+/// <a href="https://developer.riotgames.com/apis#tft-match-v1">Official API Documentation</a>
+pub struct TftMatchV1<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> TftMatchV1<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get a list of match ids by PUUID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-match-v1/GET_getMatchIdsByPUUID">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_match_ids_by_puuid(
+        &self,
+        region: Regions,
+        puuid: &str,
+        parameters: GetTftMatchIdsParameters,
+    ) -> impl Future<Item = Vec<String>, Error = Error> {
+        self.api.get_with_params(
+            region,
+            &TFT_MATCH_V1_GET_MATCH_IDS_BY_PUUID,
+            &[puuid],
+            to_query(&parameters),
+        )
+    }
+
+    /// Get a match by match id.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-match-v1/GET_getMatch">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_match(
+        &self,
+        region: Regions,
+        match_id: &str,
+    ) -> impl Future<Item = MatchDTO, Error = Error> {
+        self.api.get(region, &TFT_MATCH_V1_GET_MATCH, &[match_id])
+    }
+}
+
+/// Query parameters of `TftMatchV1::get_match_ids_by_puuid`.
+#[derive(Serialize, Default, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTftMatchIdsParameters {
+    start: Option<u32>,
+    #[serde(with = "ts_seconds_option")]
+    start_time: Option<DateTime<Utc>>,
+    #[serde(with = "ts_seconds_option")]
+    end_time: Option<DateTime<Utc>>,
+    count: Option<u32>,
+}
+
+impl GetTftMatchIdsParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start index, defaults to 0.
+    pub fn start(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Only matches played after this time.
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Only matches played before this time.
+    pub fn end_time(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Number of match ids to return. Defaults to 20.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+}
+
+/// TFT league routes take the queue as a query parameter instead of a path segment.
+#[derive(Serialize)]
+struct QueueQuery<'a> {
+    queue: &'a Queue,
+    #[serde(flatten)]
+    parameters: GetLeagueEntriesParams,
+}
+
+impl<'a> QueueQuery<'a> {
+    fn new(queue: &'a Queue, parameters: GetLeagueEntriesParams) -> Self {
+        Self { queue, parameters }
+    }
+}
@@ -0,0 +1,37 @@
+use failure::Error;
+use hyper::rt::Future;
+
+use crate::{constants::WithHost, RiotApi};
+
+use super::routes::THIRD_PARTY_CODE_V4_GET_THIRD_PARTY_CODE_BY_SUMMONER_ID;
+
+/// ThirdPartyCodeV4 endpoints.
+///
+/// <a href="https://developer.riotgames.com/apis#third-party-code-v4">Official API Documentation</a>
+pub struct ThirdPartyCodeV4<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> ThirdPartyCodeV4<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Get third party code for a given summoner ID. Resolves to `None` when the summoner has not
+    /// set a code.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#third-party-code-v4/GET_getThirdPartyCodeBySummonerId">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_third_party_code_by_summoner_id<T: WithHost>(
+        &self,
+        region: T,
+        encrypted_summoner_id: &str,
+    ) -> impl Future<Item = Option<String>, Error = Error> {
+        self.api.get_optional(
+            region,
+            &THIRD_PARTY_CODE_V4_GET_THIRD_PARTY_CODE_BY_SUMMONER_ID,
+            &[encrypted_summoner_id],
+        )
+    }
+}
@@ -0,0 +1,253 @@
+use failure::Error;
+use hyper::rt::Future;
+use serde_derive::Serialize;
+
+use crate::{
+    constants::WithHost,
+    models::{
+        LobbyEventDTOWrapper, ProviderRegistrationParameters, TournamentCodeDTO,
+        TournamentCodeParameters, TournamentCodeUpdateParameters, TournamentRegistrationParameters,
+    },
+    RiotApi,
+};
+
+use super::{
+    routes::{
+        TOURNAMENT_STUB_V4_CREATE_TOURNAMENT_CODE, TOURNAMENT_STUB_V4_GET_LOBBY_EVENTS_BY_CODE,
+        TOURNAMENT_STUB_V4_REGISTER_PROVIDER_DATA, TOURNAMENT_STUB_V4_REGISTER_TOURNAMENT,
+        TOURNAMENT_V4_CREATE_TOURNAMENT_CODE, TOURNAMENT_V4_GET_LOBBY_EVENTS_BY_CODE,
+        TOURNAMENT_V4_GET_TOURNAMENT_CODE, TOURNAMENT_V4_REGISTER_PROVIDER_DATA,
+        TOURNAMENT_V4_REGISTER_TOURNAMENT, TOURNAMENT_V4_UPDATE_CODE,
+    },
+    to_query,
+};
+
+/// TournamentV4 endpoints. Requires a tournament API key.
+///
+/// <a href="https://developer.riotgames.com/apis#tournament-v4">Official API Documentation</a>
+pub struct TournamentV4<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> TournamentV4<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Create a tournament code for the given tournament.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-v4/POST_createTournamentCode">
+    ///   Official API Documentation
+    /// </a>
+    pub fn create_tournament_code<T: WithHost>(
+        &self,
+        region: T,
+        tournament_id: i32,
+        count: u32,
+        parameters: &TournamentCodeParameters,
+    ) -> impl Future<Item = Vec<String>, Error = Error> {
+        self.api.post(
+            region,
+            &TOURNAMENT_V4_CREATE_TOURNAMENT_CODE,
+            &[],
+            to_query(&CodeQuery {
+                tournament_id,
+                count,
+            }),
+            parameters,
+        )
+    }
+
+    /// Returns the tournament code DTO associated with a tournament code string.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-v4/GET_getTournamentCode">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_tournament_code<T: WithHost>(
+        &self,
+        region: T,
+        tournament_code: &str,
+    ) -> impl Future<Item = TournamentCodeDTO, Error = Error> {
+        self.api.get(
+            region,
+            &TOURNAMENT_V4_GET_TOURNAMENT_CODE,
+            &[tournament_code],
+        )
+    }
+
+    /// Update the pick type, map, spectator type, or allowed summoners for a code.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-v4/PUT_updateCode">
+    ///   Official API Documentation
+    /// </a>
+    pub fn update_code<T: WithHost>(
+        &self,
+        region: T,
+        tournament_code: &str,
+        parameters: &TournamentCodeUpdateParameters,
+    ) -> impl Future<Item = (), Error = Error> {
+        self.api.put(
+            region,
+            &TOURNAMENT_V4_UPDATE_CODE,
+            &[tournament_code],
+            String::from(""),
+            parameters,
+        )
+    }
+
+    /// Gets a list of lobby events by tournament code.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-v4/GET_getLobbyEventsByCode">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_lobby_events_by_code<T: WithHost>(
+        &self,
+        region: T,
+        tournament_code: &str,
+    ) -> impl Future<Item = LobbyEventDTOWrapper, Error = Error> {
+        self.api.get(
+            region,
+            &TOURNAMENT_V4_GET_LOBBY_EVENTS_BY_CODE,
+            &[tournament_code],
+        )
+    }
+
+    /// Creates a tournament provider and returns its ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-v4/POST_registerProviderData">
+    ///   Official API Documentation
+    /// </a>
+    pub fn register_provider_data<T: WithHost>(
+        &self,
+        region: T,
+        parameters: &ProviderRegistrationParameters,
+    ) -> impl Future<Item = i32, Error = Error> {
+        self.api.post(
+            region,
+            &TOURNAMENT_V4_REGISTER_PROVIDER_DATA,
+            &[],
+            String::from(""),
+            parameters,
+        )
+    }
+
+    /// Creates a tournament and returns its ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-v4/POST_registerTournament">
+    ///   Official API Documentation
+    /// </a>
+    pub fn register_tournament<T: WithHost>(
+        &self,
+        region: T,
+        parameters: &TournamentRegistrationParameters,
+    ) -> impl Future<Item = i32, Error = Error> {
+        self.api.post(
+            region,
+            &TOURNAMENT_V4_REGISTER_TOURNAMENT,
+            &[],
+            String::from(""),
+            parameters,
+        )
+    }
+}
+
+/// TournamentStubV4 endpoints. Mirrors `TournamentV4` with mock data, so it can be used with a
+/// regular API key while developing. Riot doesn't offer getting or updating codes on the stub.
+///
+/// <a href="https://developer.riotgames.com/apis#tournament-stub-v4">Official API Documentation</a>
+pub struct TournamentStubV4<'a> {
+    api: &'a RiotApi,
+}
+
+impl<'a> TournamentStubV4<'a> {
+    pub fn new(api: &'a RiotApi) -> Self {
+        Self { api }
+    }
+
+    /// Create a mock tournament code for the given tournament.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-stub-v4/POST_createTournamentCode">
+    ///   Official API Documentation
+    /// </a>
+    pub fn create_tournament_code<T: WithHost>(
+        &self,
+        region: T,
+        tournament_id: i32,
+        count: u32,
+        parameters: &TournamentCodeParameters,
+    ) -> impl Future<Item = Vec<String>, Error = Error> {
+        self.api.post(
+            region,
+            &TOURNAMENT_STUB_V4_CREATE_TOURNAMENT_CODE,
+            &[],
+            to_query(&CodeQuery {
+                tournament_id,
+                count,
+            }),
+            parameters,
+        )
+    }
+
+    /// Gets a mock list of lobby events by tournament code.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-stub-v4/GET_getLobbyEventsByCode">
+    ///   Official API Documentation
+    /// </a>
+    pub fn get_lobby_events_by_code<T: WithHost>(
+        &self,
+        region: T,
+        tournament_code: &str,
+    ) -> impl Future<Item = LobbyEventDTOWrapper, Error = Error> {
+        self.api.get(
+            region,
+            &TOURNAMENT_STUB_V4_GET_LOBBY_EVENTS_BY_CODE,
+            &[tournament_code],
+        )
+    }
+
+    /// Creates a mock tournament provider and returns its ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-stub-v4/POST_registerProviderData">
+    ///   Official API Documentation
+    /// </a>
+    pub fn register_provider_data<T: WithHost>(
+        &self,
+        region: T,
+        parameters: &ProviderRegistrationParameters,
+    ) -> impl Future<Item = i32, Error = Error> {
+        self.api.post(
+            region,
+            &TOURNAMENT_STUB_V4_REGISTER_PROVIDER_DATA,
+            &[],
+            String::from(""),
+            parameters,
+        )
+    }
+
+    /// Creates a mock tournament and returns its ID.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-stub-v4/POST_registerTournament">
+    ///   Official API Documentation
+    /// </a>
+    pub fn register_tournament<T: WithHost>(
+        &self,
+        region: T,
+        parameters: &TournamentRegistrationParameters,
+    ) -> impl Future<Item = i32, Error = Error> {
+        self.api.post(
+            region,
+            &TOURNAMENT_STUB_V4_REGISTER_TOURNAMENT,
+            &[],
+            String::from(""),
+            parameters,
+        )
+    }
+}
+
+/// Query parameters of the tournament code creation endpoints.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CodeQuery {
+    tournament_id: i32,
+    count: u32,
+}
@@ -8,6 +8,10 @@ pub enum FetchError {
     Json(serde_json::Error),
     #[fail(display = "HTTP Status code: {}", _0)]
     Status(StatusCode),
+    #[fail(display = "API key expired or rejected, HTTP Status code: {}", _0)]
+    KeyExpired(StatusCode),
+    #[fail(display = "Invalid request: {}", _0)]
+    InvalidRequest(String),
 }
 
 impl From<hyper::Error> for FetchError {
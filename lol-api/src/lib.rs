@@ -1,15 +1,27 @@
 #[macro_use]
 extern crate failure;
 
+#[macro_use]
+mod macros;
+
+mod api_key;
+mod connector;
 pub mod constants;
 pub mod endpoints;
 mod error;
 mod riot_api;
+mod rso;
 
 pub mod models;
 
+#[doc(hidden)]
+pub use self::macros::__private;
+
+pub use self::api_key::*;
+pub use self::connector::Connector;
 pub use self::error::*;
 pub use self::riot_api::*;
+pub use self::rso::*;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,184 @@
+/// Defines an enum of string values sent by Riot, like queues or tiers.
+///
+/// Generates `value()`, `VARIANTS` and `variants()`, `Display`, `Debug`, a case-insensitive
+/// `FromStr`, serde support and an error type for values that can't be parsed. Values after `|`
+/// are aliases, accepted when parsing but never written.
+///
+/// Enums ending with `..Unknown` also get an `Unknown(String)` variant that keeps values added by
+/// Riot after this crate; callers that would rather fail decode them through `constants::Strict`.
+/// Every enum decoded from Riot responses ends with `..Unknown`, only enums that are sent to Riot
+/// or derived locally are closed. Holding a `String`, these enums are `Clone` but not `Copy`, and
+/// their `value()` borrows from them; closed enums are `Copy` with a `&'static str` value.
+///
+/// ```
+/// use lol_api::riot_enum;
+///
+/// riot_enum! {
+///     /// Side of the map.
+///     pub enum Side: SideError::InvalidSide("side") {
+///         Blue = "BLUE" | "100",
+///         Red = "RED" | "200",
+///         ..Unknown
+///     }
+/// }
+///
+/// assert_eq!("100".parse::<Side>().unwrap(), Side::Blue);
+/// assert_eq!(Side::variants().count(), 2);
+/// ```
+#[macro_export]
+macro_rules! riot_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $error:ident::$invalid:ident($desc:tt) {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:tt $(| $alias:tt)*,)+
+            ..$unknown:ident
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+            /// Value added by Riot after this version of the crate.
+            $unknown(String),
+        }
+
+        impl $name {
+            pub fn value(&self) -> &str {
+                match *self {
+                    $($name::$variant => $value,)+
+                    $name::$unknown(ref value) => value,
+                }
+            }
+        }
+
+        $crate::riot_enum!(@common $name, $error, $invalid, $desc, {
+            $($variant = $value $(| $alias)*,)+
+        }, |value| $crate::constants::parse_or_unknown(value, $name::$unknown));
+    };
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $error:ident::$invalid:ident($desc:tt) {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:tt $(| $alias:tt)*,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+        }
+
+        impl $name {
+            pub fn value(&self) -> &'static str {
+                match *self {
+                    $($name::$variant => $value,)+
+                }
+            }
+        }
+
+        $crate::riot_enum!(@common $name, $error, $invalid, $desc, {
+            $($variant = $value $(| $alias)*,)+
+        }, |value| value
+            .parse::<$name>()
+            .map_err(|err| $crate::__private::serde::de::Error::custom(err.to_string())));
+    };
+    (
+        @common $name:ident, $error:ident, $invalid:ident, $desc:tt, {
+            $($variant:ident = $value:tt $(| $alias:tt)*,)+
+        }, |$visited:ident| $visit:expr
+    ) => {
+        impl $name {
+            /// Every known value, in declaration order.
+            pub const VARIANTS: &'static [$name] = &[$($name::$variant,)+];
+
+            pub fn variants() -> impl Iterator<Item = $name> {
+                $name::VARIANTS.iter().cloned()
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(self.value())
+            }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(self.value())
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case($value) $(|| s.eq_ignore_ascii_case($alias))* {
+                        return Ok($name::$variant);
+                    }
+                )+
+
+                Err($error::$invalid {
+                    value: s.to_owned(),
+                })
+            }
+        }
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.serialize_str(self.value())
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl<'de> $crate::__private::serde::de::Visitor<'de> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                        f.write_str(concat!("a ", $desc, " value expected"))
+                    }
+
+                    fn visit_str<E>(self, $visited: &str) -> Result<Self::Value, E>
+                    where
+                        E: $crate::__private::serde::de::Error,
+                    {
+                        $visit
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }
+
+        #[derive(Debug)]
+        pub enum $error {
+            $invalid { value: String },
+        }
+
+        impl ::std::fmt::Display for $error {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                match *self {
+                    $error::$invalid { ref value } => {
+                        write!(f, concat!("invalid ", $desc, ": {}"), value)
+                    }
+                }
+            }
+        }
+
+        impl $crate::__private::Fail for $error {}
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use failure::Fail;
+    pub use serde;
+}
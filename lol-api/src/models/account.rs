@@ -0,0 +1,151 @@
+use serde_derive::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDTO {
+    pub puuid: String,
+    /// This field may be excluded from the response if the account doesn't have a gameName.
+    pub game_name: Option<String>,
+    /// This field may be excluded from the response if the account doesn't have a tagLine.
+    pub tag_line: Option<String>,
+}
+
+impl AccountDTO {
+    /// Riot ID of the account, if it has one.
+    pub fn riot_id(&self) -> Option<RiotId> {
+        match (&self.game_name, &self.tag_line) {
+            (Some(game_name), Some(tag_line)) => Some(RiotId {
+                game_name: game_name.clone(),
+                tag_line: tag_line.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveShardDTO {
+    pub puuid: String,
+    pub game: String,
+    pub active_shard: String,
+}
+
+/// Games with active shards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShardGame {
+    Valorant,
+    LegendsOfRuneterra,
+}
+
+impl ShardGame {
+    pub fn value(&self) -> &'static str {
+        match *self {
+            ShardGame::Valorant => "val",
+            ShardGame::LegendsOfRuneterra => "lor",
+        }
+    }
+}
+
+/// Riot ID of a player, written as `GameName#TAG`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RiotId {
+    game_name: String,
+    tag_line: String,
+}
+
+impl RiotId {
+    const GAME_NAME_LENGTH: (usize, usize) = (3, 16);
+    const TAG_LINE_LENGTH: (usize, usize) = (3, 5);
+
+    /// Builds a Riot ID, validating both parts. Game names have between 3 and 16 characters, tag
+    /// lines between 3 and 5 alphanumeric characters.
+    pub fn new(game_name: &str, tag_line: &str) -> Result<Self, RiotIdError> {
+        let name_length = game_name.chars().count();
+        if name_length < Self::GAME_NAME_LENGTH.0
+            || name_length > Self::GAME_NAME_LENGTH.1
+            || game_name.contains('#')
+        {
+            return Err(RiotIdError::InvalidGameName {
+                value: game_name.to_owned(),
+            });
+        }
+
+        let tag_length = tag_line.chars().count();
+        if tag_length < Self::TAG_LINE_LENGTH.0
+            || tag_length > Self::TAG_LINE_LENGTH.1
+            || !tag_line.chars().all(char::is_alphanumeric)
+        {
+            return Err(RiotIdError::InvalidTagLine {
+                value: tag_line.to_owned(),
+            });
+        }
+
+        Ok(Self {
+            game_name: game_name.to_owned(),
+            tag_line: tag_line.to_owned(),
+        })
+    }
+
+    pub fn game_name(&self) -> &str {
+        &self.game_name
+    }
+
+    pub fn tag_line(&self) -> &str {
+        &self.tag_line
+    }
+}
+
+impl fmt::Display for RiotId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}#{}", self.game_name, self.tag_line)
+    }
+}
+
+impl FromStr for RiotId {
+    type Err = RiotIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.rfind('#') {
+            Some(index) => RiotId::new(&s[..index], &s[index + 1..]),
+            None => Err(RiotIdError::MissingTagLine {
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Fail)]
+pub enum RiotIdError {
+    #[fail(display = "missing tag line in Riot ID: {}", value)]
+    MissingTagLine { value: String },
+    #[fail(display = "invalid game name: {}", value)]
+    InvalidGameName { value: String },
+    #[fail(display = "invalid tag line: {}", value)]
+    InvalidTagLine { value: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_riot_id() {
+        let riot_id = "Hide on bush#KR1".parse::<RiotId>().unwrap();
+
+        assert_eq!(riot_id.game_name(), "Hide on bush");
+        assert_eq!(riot_id.tag_line(), "KR1");
+        assert_eq!(riot_id.to_string(), "Hide on bush#KR1");
+    }
+
+    #[test]
+    fn rejects_invalid_riot_ids() {
+        assert!("Faker".parse::<RiotId>().is_err());
+        assert!("Fa#KR1".parse::<RiotId>().is_err());
+        assert!("Faker#K1".parse::<RiotId>().is_err());
+        assert!("Faker#KR-1".parse::<RiotId>().is_err());
+        assert!("A name way too long#EUW".parse::<RiotId>().is_err());
+    }
+}
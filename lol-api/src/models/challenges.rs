@@ -0,0 +1,127 @@
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::constants::ChallengeLevel;
+
+/// Configuration of a single challenge.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeConfigInfoDTO {
+    pub id: i64,
+    /// Name, short description and description, keyed by locale then by field.
+    pub localized_names: HashMap<String, HashMap<String, String>>,
+    pub state: ChallengeState,
+    pub tracking: Option<ChallengeTracking>,
+    /// Epoch milliseconds.
+    pub start_timestamp: Option<i64>,
+    /// Epoch milliseconds.
+    pub end_timestamp: Option<i64>,
+    /// Whether the challenge has an apex leaderboard.
+    pub leaderboard: bool,
+    /// Value needed to reach each level.
+    pub thresholds: HashMap<ChallengeLevel, f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChallengeState {
+    Disabled,
+    Hidden,
+    Enabled,
+    Archived,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChallengeTracking {
+    Lifetime,
+    Season,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Entry of an apex level leaderboard.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApexPlayerInfoDTO {
+    pub puuid: String,
+    pub value: f64,
+    pub position: u32,
+}
+
+/// Challenge progress of a player.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerInfoDTO {
+    pub challenges: Vec<ChallengeInfo>,
+    pub preferences: PlayerClientPreferences,
+    pub total_points: ChallengePoints,
+    /// Points keyed by category, like `VETERANCY` or `TEAMWORK`.
+    pub category_points: HashMap<String, ChallengePoints>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeInfo {
+    pub challenge_id: i64,
+    pub percentile: f64,
+    pub level: ChallengeLevel,
+    pub value: f64,
+    /// Epoch milliseconds.
+    pub achieved_time: Option<i64>,
+    /// Leaderboard position, only set for apex levels.
+    pub position: Option<u32>,
+    pub players_in_level: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengePoints {
+    pub level: ChallengeLevel,
+    pub current: i64,
+    pub max: i64,
+    pub percentile: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerClientPreferences {
+    pub banner_accent: Option<String>,
+    pub title: Option<String>,
+    /// Challenges displayed as tokens on the profile.
+    pub challenge_ids: Option<Vec<i64>>,
+    pub crest_border: Option<String>,
+    pub prestige_crest_border_level: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_levels() {
+        let config: ChallengeConfigInfoDTO = serde_json::from_str(
+            r#"{
+                "id": 101000,
+                "localizedNames": {"en_US": {"name": "ARAM Authority"}},
+                "state": "ENABLED",
+                "leaderboard": true,
+                "thresholds": {"IRON": 1.0, "GRANDMASTER": 1450.0}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.state, ChallengeState::Enabled);
+        assert_eq!(config.tracking, None);
+        assert_eq!(config.thresholds[&ChallengeLevel::GrandMaster], 1450.0);
+        assert_eq!(
+            ChallengeLevel::GrandMaster
+                .tier()
+                .map(|tier| tier.to_string()),
+            Some("GRANDMASTER".to_owned())
+        );
+        assert!(ChallengeLevel::None.tier().is_none());
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+/// Clash registration of a player.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashPlayerDTO {
+    pub summoner_id: String,
+    /// Not present while the player is looking for a team.
+    pub team_id: Option<String>,
+    pub position: ClashPosition,
+    pub role: ClashRole,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashTeamDTO {
+    pub id: String,
+    pub tournament_id: u32,
+    pub name: String,
+    pub icon_id: u32,
+    pub tier: ClashTier,
+    /// Summoner ID of the team captain.
+    pub captain: String,
+    pub abbreviation: String,
+    /// Team members.
+    pub players: Vec<ClashPlayerDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashTournamentDTO {
+    pub id: u32,
+    pub theme_id: u32,
+    pub name_key: String,
+    pub name_key_secondary: String,
+    /// Tournament phase.
+    pub schedule: Vec<ClashTournamentPhaseDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashTournamentPhaseDTO {
+    pub id: u32,
+    #[serde(with = "ts_milliseconds")]
+    pub registration_time: DateTime<Utc>,
+    #[serde(with = "ts_milliseconds")]
+    pub start_time: DateTime<Utc>,
+    pub cancelled: bool,
+}
+
+/// Tier of a Clash team, from `1`, the highest, to `4`. Teams are placed in a tier from the ranks
+/// of their members, and only play against teams of the same tier.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct ClashTier(pub u8);
+
+/// Position a player registered for.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ClashPosition {
+    Unselected,
+    Fill,
+    Top,
+    Jungle,
+    Middle,
+    Bottom,
+    Utility,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Role of a player within their team.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ClashRole {
+    Captain,
+    Member,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_team() {
+        let team: ClashTeamDTO = serde_json::from_str(
+            r#"{
+                "id": "2f3b4c11-6d1a-4e52-a8c4-1f0b9e7d6c5a",
+                "tournamentId": 2102,
+                "name": "Mid or Feed",
+                "iconId": 43,
+                "tier": 3,
+                "captain": "captain-id",
+                "abbreviation": "MOF",
+                "players": [
+                    { "summonerId": "captain-id", "position": "UTILITY", "role": "CAPTAIN" },
+                    { "summonerId": "member-id", "position": "ROAMING", "role": "SUBSTITUTE" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(team.tier, ClashTier(3));
+        assert_eq!(team.players[0].position, ClashPosition::Utility);
+        assert_eq!(team.players[0].role, ClashRole::Captain);
+        assert_eq!(team.players[1].position, ClashPosition::Unknown);
+        assert_eq!(team.players[1].role, ClashRole::Unknown);
+        assert_eq!(serde_json::to_value(team.tier).unwrap(), 3);
+    }
+}
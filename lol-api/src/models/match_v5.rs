@@ -0,0 +1,794 @@
+//! Match-v5 DTOs. Kept in their own module since Riot reuses most of the match-v4 names.
+
+use chrono::serde::{ts_milliseconds, ts_milliseconds_option};
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::constants::Champion;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchDTO {
+    /// Match metadata.
+    pub metadata: MetadataDTO,
+    /// Match info.
+    pub info: InfoDTO,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataDTO {
+    /// Match data version.
+    pub data_version: String,
+    /// Match id, like `EUW1_123`.
+    pub match_id: String,
+    /// A list of participant PUUIDs.
+    pub participants: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoDTO {
+    /// Refer to indicate if the game ended in termination.
+    pub end_of_game_result: Option<String>,
+    /// Unix timestamp for when the game is created on the game server (i.e., the loading screen).
+    #[serde(with = "ts_milliseconds")]
+    pub game_creation: DateTime<Utc>,
+    /// Prior to patch 11.20, this field returns the game length in milliseconds calculated from
+    /// gameEndTimestamp - gameStartTimestamp. Post patch 11.20, this field returns the max
+    /// timePlayed of any participant in the game in seconds. Use `game_end_timestamp` to tell them
+    /// apart: it is only present for games played after the change.
+    pub game_duration: u64,
+    /// Unix timestamp for when the match ends on the game server. Added in patch 11.20.
+    #[serde(default, with = "ts_milliseconds_option")]
+    pub game_end_timestamp: Option<DateTime<Utc>>,
+    pub game_id: u64,
+    /// Refer to the Game Constants documentation.
+    pub game_mode: String,
+    pub game_name: String,
+    /// Unix timestamp for when match starts on the game server.
+    #[serde(with = "ts_milliseconds")]
+    pub game_start_timestamp: DateTime<Utc>,
+    pub game_type: String,
+    /// The first two parts can be used to determine the patch a game was played on.
+    pub game_version: String,
+    /// Refer to the Game Constants documentation.
+    pub map_id: u32,
+    pub participants: Vec<ParticipantDTO>,
+    /// Platform where the match was played.
+    pub platform_id: String,
+    /// Refer to the Game Constants documentation.
+    pub queue_id: u32,
+    pub teams: Vec<TeamDTO>,
+    /// Tournament code used to generate the match. Added in patch 11.13.
+    pub tournament_code: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantDTO {
+    pub all_in_pings: Option<u32>,
+    pub assist_me_pings: Option<u32>,
+    pub assists: u32,
+    pub baron_kills: u32,
+    pub bounty_level: Option<u32>,
+    pub champ_experience: u32,
+    pub champ_level: u32,
+    /// Prior to patch 11.4, on Feb 18th, 2021, this field returned invalid championIds. We
+    /// recommend determining the champion based on the championName field for matches played prior
+    /// to patch 11.4.
+    pub champion_id: Champion,
+    pub champion_name: String,
+    pub command_pings: Option<u32>,
+    /// This field is currently only utilized for Kayn's transformations. (Legal values: 0 - None,
+    /// 1 - Slayer, 2 - Assassin)
+    pub champion_transform: u32,
+    pub consumables_purchased: u32,
+    pub challenges: Option<ChallengesDTO>,
+    pub damage_dealt_to_buildings: Option<u64>,
+    pub damage_dealt_to_objectives: u64,
+    pub damage_dealt_to_turrets: u64,
+    pub damage_self_mitigated: u64,
+    pub deaths: u32,
+    pub detector_wards_placed: u32,
+    pub double_kills: u32,
+    pub dragon_kills: u32,
+    pub eligible_for_progression: Option<bool>,
+    pub enemy_missing_pings: Option<u32>,
+    pub enemy_vision_pings: Option<u32>,
+    pub first_blood_assist: bool,
+    pub first_blood_kill: bool,
+    pub first_tower_assist: bool,
+    pub first_tower_kill: bool,
+    /// Whether the game was remade, the early vote a team can call in the first minutes when a
+    /// player is missing. Such games don't count, unlike regular surrenders.
+    pub game_ended_in_early_surrender: bool,
+    pub game_ended_in_surrender: bool,
+    pub hold_pings: Option<u32>,
+    pub get_back_pings: Option<u32>,
+    pub gold_earned: u32,
+    pub gold_spent: u32,
+    /// Both individualPosition and teamPosition are computed by the game server and are different
+    /// versions of the most likely position played by a player. The individualPosition is the best
+    /// guess for which position the player actually played in isolation of anything else. The
+    /// teamPosition is the best guess for which position the player actually played if we add the
+    /// constraint that each team must have one top player, one jungle, one middle, etc. Generally
+    /// the recommendation is to use the teamPosition field over the individualPosition field.
+    pub individual_position: String,
+    pub inhibitor_kills: u32,
+    pub inhibitor_takedowns: Option<u32>,
+    pub inhibitors_lost: Option<u32>,
+    pub item0: u32,
+    pub item1: u32,
+    pub item2: u32,
+    pub item3: u32,
+    pub item4: u32,
+    pub item5: u32,
+    pub item6: u32,
+    pub items_purchased: u32,
+    pub killing_sprees: u32,
+    pub kills: u32,
+    pub lane: String,
+    pub largest_critical_strike: u32,
+    pub largest_killing_spree: u32,
+    pub largest_multi_kill: u32,
+    pub longest_time_spent_living: u32,
+    pub magic_damage_dealt: u64,
+    pub magic_damage_dealt_to_champions: u64,
+    pub magic_damage_taken: u64,
+    pub neutral_minions_killed: u32,
+    pub need_vision_pings: Option<u32>,
+    pub nexus_kills: u32,
+    pub nexus_takedowns: Option<u32>,
+    pub nexus_lost: Option<u32>,
+    pub objectives_stolen: u32,
+    pub objectives_stolen_assists: u32,
+    pub on_my_way_pings: Option<u32>,
+    pub participant_id: u32,
+    pub player_score0: Option<f64>,
+    pub player_score1: Option<f64>,
+    pub player_score2: Option<f64>,
+    pub player_score3: Option<f64>,
+    pub player_score4: Option<f64>,
+    pub player_score5: Option<f64>,
+    pub player_score6: Option<f64>,
+    pub player_score7: Option<f64>,
+    pub player_score8: Option<f64>,
+    pub player_score9: Option<f64>,
+    pub player_score10: Option<f64>,
+    pub player_score11: Option<f64>,
+    pub penta_kills: u32,
+    pub perks: PerksDTO,
+    pub physical_damage_dealt: u64,
+    pub physical_damage_dealt_to_champions: u64,
+    pub physical_damage_taken: u64,
+    /// Arena placement.
+    pub placement: Option<u32>,
+    pub player_augment1: Option<u32>,
+    pub player_augment2: Option<u32>,
+    pub player_augment3: Option<u32>,
+    pub player_augment4: Option<u32>,
+    pub player_subteam_id: Option<u32>,
+    pub push_pings: Option<u32>,
+    pub profile_icon: u32,
+    pub puuid: String,
+    pub quadra_kills: u32,
+    pub riot_id_game_name: Option<String>,
+    pub riot_id_tagline: Option<String>,
+    pub role: String,
+    pub sight_wards_bought_in_game: u32,
+    pub spell1_casts: u32,
+    pub spell2_casts: u32,
+    pub spell3_casts: u32,
+    pub spell4_casts: u32,
+    pub subteam_placement: Option<u32>,
+    pub summoner1_casts: u32,
+    pub summoner1_id: u32,
+    pub summoner2_casts: u32,
+    pub summoner2_id: u32,
+    pub summoner_id: String,
+    pub summoner_level: u32,
+    pub summoner_name: String,
+    pub team_early_surrendered: bool,
+    pub team_id: u32,
+    /// Both individualPosition and teamPosition are computed by the game server and are different
+    /// versions of the most likely position played by a player. See `individual_position`.
+    pub team_position: String,
+    #[serde(rename = "timeCCingOthers")]
+    pub time_ccing_others: u32,
+    pub time_played: u32,
+    pub total_ally_jungle_minions_killed: Option<u32>,
+    pub total_damage_dealt: u64,
+    pub total_damage_dealt_to_champions: u64,
+    pub total_damage_shielded_on_teammates: u64,
+    pub total_damage_taken: u64,
+    pub total_enemy_jungle_minions_killed: Option<u32>,
+    /// Whenever positive health is applied (which translates to all heals in the game but not
+    /// things like regeneration), totalHeal is incremented by the amount of health received. This
+    /// includes healing enemies, jungle monsters, yourself, etc
+    pub total_heal: u64,
+    /// Whenever positive health is applied (which translates to all heals in the game but not
+    /// things like regeneration), totalHealsOnTeammates is incremented by the amount of health
+    /// received. This is post modified, so if you heal someone missing 5 health for 100 you will
+    /// get +5 totalHealsOnTeammates
+    pub total_heals_on_teammates: u64,
+    pub total_minions_killed: u32,
+    #[serde(rename = "totalTimeCCDealt")]
+    pub total_time_cc_dealt: u32,
+    pub total_time_spent_dead: u32,
+    pub total_units_healed: u32,
+    pub triple_kills: u32,
+    pub true_damage_dealt: u64,
+    pub true_damage_dealt_to_champions: u64,
+    pub true_damage_taken: u64,
+    pub turret_kills: u32,
+    pub turret_takedowns: Option<u32>,
+    pub turrets_lost: Option<u32>,
+    pub unreal_kills: u32,
+    pub vision_score: u32,
+    pub vision_cleared_pings: Option<u32>,
+    pub vision_wards_bought_in_game: u32,
+    pub wards_killed: u32,
+    pub wards_placed: u32,
+    pub win: bool,
+}
+
+/// Challenge progress made during the match. Riot keeps adding challenges, the ones without a
+/// dedicated field end up in `other`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengesDTO {
+    #[serde(rename = "12AssistStreakCount")]
+    pub assist_streak_count_12: Option<f64>,
+    pub ability_uses: Option<f64>,
+    pub aces_before15_minutes: Option<f64>,
+    pub allied_jungle_monster_kills: Option<f64>,
+    pub baron_buff_gold_advantage_over_threshold: Option<f64>,
+    pub baron_takedowns: Option<f64>,
+    pub blast_cone_opposite_opponent_count: Option<f64>,
+    pub bounty_gold: Option<f64>,
+    pub buffs_stolen: Option<f64>,
+    pub complete_support_quest_in_time: Option<f64>,
+    pub control_ward_time_coverage_in_river_or_enemy_half: Option<f64>,
+    pub control_wards_placed: Option<f64>,
+    pub damage_per_minute: Option<f64>,
+    pub damage_taken_on_team_percentage: Option<f64>,
+    pub danced_with_rift_herald: Option<f64>,
+    pub deaths_by_enemy_champs: Option<f64>,
+    pub dodge_skill_shots_small_window: Option<f64>,
+    pub double_aces: Option<f64>,
+    pub dragon_takedowns: Option<f64>,
+    pub earliest_baron: Option<f64>,
+    pub earliest_dragon_takedown: Option<f64>,
+    pub earliest_elder_dragon: Option<f64>,
+    pub early_laning_phase_gold_exp_advantage: Option<f64>,
+    pub effective_heal_and_shielding: Option<f64>,
+    pub elder_dragon_kills_with_opposing_soul: Option<f64>,
+    pub elder_dragon_multikills: Option<f64>,
+    pub enemy_champion_immobilizations: Option<f64>,
+    pub enemy_jungle_monster_kills: Option<f64>,
+    pub epic_monster_kills_near_enemy_jungler: Option<f64>,
+    pub epic_monster_kills_within30_seconds_of_spawn: Option<f64>,
+    pub epic_monster_steals: Option<f64>,
+    pub epic_monster_stolen_without_smite: Option<f64>,
+    pub first_turret_killed: Option<f64>,
+    pub first_turret_killed_time: Option<f64>,
+    pub flawless_aces: Option<f64>,
+    pub full_team_takedown: Option<f64>,
+    pub game_length: Option<f64>,
+    pub get_takedowns_in_all_lanes_early_jungle_as_laner: Option<f64>,
+    pub gold_per_minute: Option<f64>,
+    pub had_afk_teammate: Option<f64>,
+    pub had_open_nexus: Option<f64>,
+    pub highest_champion_damage: Option<f64>,
+    pub highest_crowd_control_score: Option<f64>,
+    pub highest_ward_kills: Option<f64>,
+    pub immobilize_and_kill_with_ally: Option<f64>,
+    pub initial_buff_count: Option<f64>,
+    pub initial_crab_count: Option<f64>,
+    pub jungle_cs_before10_minutes: Option<f64>,
+    pub jungler_kills_early_jungle: Option<f64>,
+    pub jungler_takedowns_near_damaged_epic_monster: Option<f64>,
+    pub k_turrets_destroyed_before_plates_fall: Option<f64>,
+    pub kda: Option<f64>,
+    pub kill_after_hidden_with_ally: Option<f64>,
+    pub kill_participation: Option<f64>,
+    pub killed_champ_took_full_team_damage_survived: Option<f64>,
+    pub killing_sprees: Option<f64>,
+    pub kills_near_enemy_turret: Option<f64>,
+    pub kills_on_laners_early_jungle_as_jungler: Option<f64>,
+    pub kills_on_other_lanes_early_jungle_as_laner: Option<f64>,
+    pub kills_on_recently_healed_by_aram_pack: Option<f64>,
+    pub kills_under_own_turret: Option<f64>,
+    pub kills_with_help_from_epic_monster: Option<f64>,
+    pub knock_enemy_into_team_and_kill: Option<f64>,
+    pub land_skill_shots_early_game: Option<f64>,
+    pub lane_minions_first10_minutes: Option<f64>,
+    pub laning_phase_gold_exp_advantage: Option<f64>,
+    pub legendary_count: Option<f64>,
+    pub lost_an_inhibitor: Option<f64>,
+    pub max_cs_advantage_on_lane_opponent: Option<f64>,
+    pub max_kill_deficit: Option<f64>,
+    pub max_level_lead_lane_opponent: Option<f64>,
+    pub mejais_full_stack_in_time: Option<f64>,
+    pub more_enemy_jungle_than_opponent: Option<f64>,
+    pub multi_kill_one_spell: Option<f64>,
+    pub multi_turret_rift_herald_count: Option<f64>,
+    pub multikills: Option<f64>,
+    pub multikills_after_aggressive_flash: Option<f64>,
+    pub mythic_item_used: Option<f64>,
+    pub outer_turret_executes_before10_minutes: Option<f64>,
+    pub outnumbered_kills: Option<f64>,
+    pub outnumbered_nexus_kill: Option<f64>,
+    pub perfect_dragon_souls_taken: Option<f64>,
+    pub perfect_game: Option<f64>,
+    pub pick_kill_with_ally: Option<f64>,
+    pub played_champ_select_position: Option<f64>,
+    pub poro_explosions: Option<f64>,
+    pub quick_cleanse: Option<f64>,
+    pub quick_first_turret: Option<f64>,
+    pub quick_solo_kills: Option<f64>,
+    pub rift_herald_takedowns: Option<f64>,
+    pub save_ally_from_death: Option<f64>,
+    pub scuttle_crab_kills: Option<f64>,
+    pub shortest_time_to_ace_from_first_takedown: Option<f64>,
+    pub skillshots_dodged: Option<f64>,
+    pub skillshots_hit: Option<f64>,
+    pub snowballs_hit: Option<f64>,
+    pub solo_baron_kills: Option<f64>,
+    pub solo_kills: Option<f64>,
+    pub solo_turrets_lategame: Option<f64>,
+    pub stealth_wards_placed: Option<f64>,
+    pub survived_single_digit_hp_count: Option<f64>,
+    pub survived_three_immobilizes_in_fight: Option<f64>,
+    pub takedown_on_first_turret: Option<f64>,
+    pub takedowns: Option<f64>,
+    pub takedowns_after_gaining_level_advantage: Option<f64>,
+    pub takedowns_before_jungle_minion_spawn: Option<f64>,
+    pub takedowns_first_x_minutes: Option<f64>,
+    pub takedowns_in_alcove: Option<f64>,
+    pub takedowns_in_enemy_fountain: Option<f64>,
+    pub team_baron_kills: Option<f64>,
+    pub team_damage_percentage: Option<f64>,
+    pub team_elder_dragon_kills: Option<f64>,
+    pub team_rift_herald_kills: Option<f64>,
+    pub teleport_takedowns: Option<f64>,
+    pub third_inhibitor_destroyed_time: Option<f64>,
+    pub three_wards_one_sweeper_count: Option<f64>,
+    pub took_large_damage_survived: Option<f64>,
+    pub turret_plates_taken: Option<f64>,
+    pub turret_takedowns: Option<f64>,
+    pub turrets_taken_with_rift_herald: Option<f64>,
+    pub twenty_minions_in3_seconds_count: Option<f64>,
+    pub two_wards_one_sweeper_count: Option<f64>,
+    pub unseen_recalls: Option<f64>,
+    pub vision_score_advantage_lane_opponent: Option<f64>,
+    pub vision_score_per_minute: Option<f64>,
+    pub ward_takedowns: Option<f64>,
+    pub ward_takedowns_before20_m: Option<f64>,
+    pub wards_guarded: Option<f64>,
+    /// Challenges without a dedicated field.
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PerksDTO {
+    pub stat_perks: PerkStatsDTO,
+    pub styles: Vec<PerkStyleDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PerkStatsDTO {
+    pub defense: u32,
+    pub flex: u32,
+    pub offense: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PerkStyleDTO {
+    /// `primaryStyle` or `subStyle`.
+    pub description: String,
+    pub selections: Vec<PerkStyleSelectionDTO>,
+    pub style: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PerkStyleSelectionDTO {
+    pub perk: u32,
+    pub var1: i32,
+    pub var2: i32,
+    pub var3: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamDTO {
+    pub bans: Vec<BanDTO>,
+    pub objectives: ObjectivesDTO,
+    /// 100 for blue side. 200 for red side.
+    pub team_id: u32,
+    pub win: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BanDTO {
+    /// Banned champion ID, `-1` when no champion was banned.
+    pub champion_id: Champion,
+    pub pick_turn: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectivesDTO {
+    pub baron: ObjectiveDTO,
+    pub champion: ObjectiveDTO,
+    pub dragon: ObjectiveDTO,
+    /// Void grubs, added in 2024.
+    pub horde: Option<ObjectiveDTO>,
+    pub inhibitor: ObjectiveDTO,
+    pub rift_herald: ObjectiveDTO,
+    pub tower: ObjectiveDTO,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectiveDTO {
+    pub first: bool,
+    pub kills: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineDTO {
+    /// Match metadata.
+    pub metadata: MetadataDTO,
+    /// Timeline info.
+    pub info: TimelineInfoDTO,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineInfoDTO {
+    /// Refer to indicate if the game ended in termination.
+    pub end_of_game_result: Option<String>,
+    pub frame_interval: u64,
+    pub frames: Vec<FrameDTO>,
+    pub game_id: u64,
+    pub participants: Vec<TimelineParticipantDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineParticipantDTO {
+    pub participant_id: u32,
+    pub puuid: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameDTO {
+    pub events: Vec<MatchEvent>,
+    /// Frames keyed by participant ID.
+    pub participant_frames: HashMap<u32, ParticipantFrameDTO>,
+    pub timestamp: u64,
+}
+
+/// Event of a match timeline. Each kind of event only holds its own fields, events of an unknown
+/// kind are decoded as `Unknown`. `timestamp` is the game time of the event, in milliseconds.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MatchEvent {
+    #[serde(rename_all = "camelCase")]
+    PauseEnd {
+        timestamp: u64,
+        #[serde(with = "ts_milliseconds")]
+        real_timestamp: DateTime<Utc>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ChampionKill {
+        timestamp: u64,
+        position: PositionDTO,
+        killer_id: u32,
+        victim_id: u32,
+        #[serde(default)]
+        assisting_participant_ids: Vec<u32>,
+        bounty: u32,
+        shutdown_bounty: u32,
+        kill_streak_length: u32,
+        #[serde(default)]
+        victim_damage_dealt: Vec<DamageDTO>,
+        #[serde(default)]
+        victim_damage_received: Vec<DamageDTO>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ChampionSpecialKill {
+        timestamp: u64,
+        position: PositionDTO,
+        killer_id: u32,
+        /// (Legal values: KILL_ACE, KILL_FIRST_BLOOD, KILL_MULTI)
+        kill_type: String,
+        /// Number of kills, for `KILL_MULTI`.
+        multi_kill_length: Option<u32>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ChampionTransform {
+        timestamp: u64,
+        participant_id: u32,
+        /// (Legal values: ASSASSIN, SLAYER)
+        transform_type: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    WardPlaced {
+        timestamp: u64,
+        ward_type: String,
+        creator_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    WardKill {
+        timestamp: u64,
+        ward_type: String,
+        killer_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    BuildingKill {
+        timestamp: u64,
+        position: PositionDTO,
+        killer_id: u32,
+        #[serde(default)]
+        assisting_participant_ids: Vec<u32>,
+        /// Team owning the building.
+        team_id: u32,
+        /// (Legal values: INHIBITOR_BUILDING, TOWER_BUILDING)
+        building_type: String,
+        /// (Legal values: BOT_LANE, MID_LANE, TOP_LANE)
+        lane_type: String,
+        /// (Legal values: BASE_TURRET, FOUNTAIN_TURRET, INNER_TURRET, NEXUS_TURRET, OUTER_TURRET,
+        /// UNDEFINED_TURRET)
+        tower_type: Option<String>,
+        bounty: Option<u32>,
+    },
+    #[serde(rename_all = "camelCase")]
+    TurretPlateDestroyed {
+        timestamp: u64,
+        position: PositionDTO,
+        killer_id: u32,
+        /// Team owning the turret.
+        team_id: u32,
+        lane_type: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    EliteMonsterKill {
+        timestamp: u64,
+        position: PositionDTO,
+        killer_id: u32,
+        killer_team_id: u32,
+        #[serde(default)]
+        assisting_participant_ids: Vec<u32>,
+        /// (Legal values: ATAKHAN, BARON_NASHOR, DRAGON, HORDE, RIFTHERALD)
+        monster_type: String,
+        /// Kind of dragon, for `DRAGON` kills.
+        monster_sub_type: Option<String>,
+        bounty: Option<u32>,
+    },
+    #[serde(rename_all = "camelCase")]
+    DragonSoulGiven {
+        timestamp: u64,
+        team_id: u32,
+        /// Kind of soul, like `Infernal`.
+        name: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemPurchased {
+        timestamp: u64,
+        participant_id: u32,
+        item_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemSold {
+        timestamp: u64,
+        participant_id: u32,
+        item_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemDestroyed {
+        timestamp: u64,
+        participant_id: u32,
+        item_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemUndo {
+        timestamp: u64,
+        participant_id: u32,
+        /// Item given back, 0 when undoing a sale.
+        before_id: u32,
+        /// Item bought back, 0 when undoing a purchase.
+        after_id: u32,
+        gold_gain: i32,
+    },
+    #[serde(rename_all = "camelCase")]
+    SkillLevelUp {
+        timestamp: u64,
+        participant_id: u32,
+        skill_slot: u32,
+        /// (Legal values: EVOLVE, NORMAL)
+        level_up_type: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    LevelUp {
+        timestamp: u64,
+        participant_id: u32,
+        level: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ObjectiveBountyPrestart {
+        timestamp: u64,
+        team_id: u32,
+        /// Game time the bounty becomes active, in milliseconds.
+        actual_start_time: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    ObjectiveBountyFinish { timestamp: u64, team_id: u32 },
+    #[serde(rename_all = "camelCase")]
+    FeatUpdate {
+        timestamp: u64,
+        team_id: u32,
+        feat_type: u32,
+        feat_value: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    GameEnd {
+        timestamp: u64,
+        #[serde(with = "ts_milliseconds")]
+        real_timestamp: DateTime<Utc>,
+        game_id: u64,
+        winning_team: u32,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DamageDTO {
+    pub basic: bool,
+    pub magic_damage: u32,
+    pub name: String,
+    pub participant_id: u32,
+    pub physical_damage: u32,
+    pub spell_name: String,
+    pub spell_slot: i32,
+    pub true_damage: u32,
+    pub r#type: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantFrameDTO {
+    pub champion_stats: ChampionStatsDTO,
+    pub current_gold: i32,
+    pub damage_stats: DamageStatsDTO,
+    pub gold_per_second: u32,
+    pub jungle_minions_killed: u32,
+    pub level: u32,
+    pub minions_killed: u32,
+    pub participant_id: u32,
+    pub position: PositionDTO,
+    pub time_enemy_spent_controlled: u32,
+    pub total_gold: u32,
+    pub xp: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampionStatsDTO {
+    pub ability_haste: i32,
+    pub ability_power: i32,
+    pub armor: i32,
+    pub armor_pen: i32,
+    pub armor_pen_percent: i32,
+    pub attack_damage: i32,
+    pub attack_speed: i32,
+    pub bonus_armor_pen_percent: i32,
+    pub bonus_magic_pen_percent: i32,
+    pub cc_reduction: i32,
+    pub cooldown_reduction: i32,
+    pub health: i32,
+    pub health_max: i32,
+    pub health_regen: i32,
+    pub lifesteal: i32,
+    pub magic_pen: i32,
+    pub magic_pen_percent: i32,
+    pub magic_resist: i32,
+    pub movement_speed: i32,
+    pub omnivamp: i32,
+    pub physical_vamp: i32,
+    pub power: i32,
+    pub power_max: i32,
+    pub power_regen: i32,
+    pub spell_vamp: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DamageStatsDTO {
+    pub magic_damage_done: u64,
+    pub magic_damage_done_to_champions: u64,
+    pub magic_damage_taken: u64,
+    pub physical_damage_done: u64,
+    pub physical_damage_done_to_champions: u64,
+    pub physical_damage_taken: u64,
+    pub total_damage_done: u64,
+    pub total_damage_done_to_champions: u64,
+    pub total_damage_taken: u64,
+    pub true_damage_done: u64,
+    pub true_damage_done_to_champions: u64,
+    pub true_damage_taken: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionDTO {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_unknown_challenges() {
+        let challenges: ChallengesDTO = serde_json::from_str(
+            r#"{"12AssistStreakCount": 1, "kda": 4.5, "SWARM_DefeatAatrox": 0}"#,
+        )
+        .unwrap();
+
+        assert_eq!(challenges.assist_streak_count_12, Some(1.0));
+        assert_eq!(challenges.kda, Some(4.5));
+        assert_eq!(challenges.solo_kills, None);
+        assert_eq!(challenges.other["SWARM_DefeatAatrox"], 0);
+    }
+
+    #[test]
+    fn deserializes_timeline_frames() {
+        let frame: FrameDTO = serde_json::from_str(
+            r#"{
+                "events": [{"type": "PAUSE_END", "timestamp": 0, "realTimestamp": 1625000000000}],
+                "participantFrames": {
+                    "1": {
+                        "championStats": {"armor": 30},
+                        "currentGold": 500,
+                        "damageStats": {},
+                        "goldPerSecond": 0,
+                        "jungleMinionsKilled": 0,
+                        "level": 1,
+                        "minionsKilled": 0,
+                        "participantId": 1,
+                        "position": {"x": 554, "y": 581},
+                        "timeEnemySpentControlled": 0,
+                        "totalGold": 500,
+                        "xp": 0
+                    }
+                },
+                "timestamp": 0
+            }"#,
+        )
+        .unwrap();
+
+        match &frame.events[0] {
+            MatchEvent::PauseEnd { real_timestamp, .. } => {
+                assert_eq!(real_timestamp.timestamp(), 1_625_000_000)
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+        assert_eq!(frame.participant_frames[&1].champion_stats.armor, 30);
+        assert_eq!(frame.participant_frames[&1].position.x, 554);
+    }
+}
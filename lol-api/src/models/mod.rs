@@ -0,0 +1,839 @@
+mod account;
+mod challenges;
+mod clash;
+pub mod match_v5;
+mod spectator;
+mod status;
+pub mod tft;
+mod tournament;
+
+pub use self::account::*;
+pub use self::challenges::*;
+pub use self::clash::*;
+pub use self::spectator::*;
+pub use self::status::*;
+pub use self::tournament::*;
+
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize as _, Deserializer};
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::constants::{
+    parse_or_unknown, Champion, Division, Lane, Position, Queue, Rank, Role, Tier,
+};
+
+/// Represents a summoner
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SummonerDTO {
+    /// ID of the summoner icon associated with the summoner.
+    pub profile_icon_id: u32,
+    /// Summoner name.
+    pub name: String,
+    /// Encrypted PUUID. Exact length of 78 characters.
+    pub puuid: String,
+    ///  Summoner level associated with the summoner.
+    pub summoner_level: u32,
+    /// Date summoner was last modified specified as epoch milliseconds. The following events will
+    /// update this timestamp: profile icon change, playing the tutorial or advanced tutorial,
+    /// finishing a game, summoner name change
+    #[serde(with = "ts_milliseconds")]
+    pub revision_date: DateTime<Utc>,
+    /// Encrypted summoner ID. Max length 63 characters.
+    pub id: String,
+    /// Encrypted account ID. Max length 56 characters.
+    pub account_id: String,
+}
+
+/// This object contains single Champion Mastery information for player and champion combination.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionMasteryDTO {
+    /// Is chest granted for this champion or not in current season.
+    pub chest_granted: bool,
+    /// Champion level for specified player and champion combination.
+    pub champion_level: u32,
+    /// Total number of champion points for this player and champion combination - they are used to
+    /// determine championLevel.
+    pub champion_points: u32,
+    /// Champion ID for this entry.
+    pub champion_id: Champion,
+    /// Number of points needed to achieve next level. Zero if player reached maximum champion
+    /// level for this champion.
+    pub champion_points_until_next_level: u64,
+    /// Last time this champion was played by this player - in Unix milliseconds time format.
+    #[serde(with = "ts_milliseconds")]
+    pub last_play_time: DateTime<Utc>,
+    /// The token earned for this champion to levelup.
+    pub tokens_earned: u32,
+    /// Number of points earned since current level has been achieved.
+    pub champion_points_since_last_level: u64,
+    ///  Summoner ID for this entry. (Encrypted) Not included in the by-PUUID responses.
+    pub summoner_id: Option<String>,
+    /// Player Universal Unique Identifier for this entry. (Encrypted)
+    pub puuid: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionInfo {
+    pub free_champion_ids: Vec<Champion>,
+    pub free_champion_ids_for_new_players: Vec<Champion>,
+    pub max_new_player_level: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueEntryDTO {
+    pub summoner_id: String,
+    pub summoner_name: String,
+    pub league_id: String,
+    pub queue_type: Queue,
+    pub hot_streak: bool,
+    pub wins: u32,
+    pub losses: u32,
+    pub rank: Division,
+    pub tier: Tier,
+    pub league_points: u32,
+    pub veteran: bool,
+    pub fresh_blood: bool,
+    pub inactive: bool,
+}
+
+impl LeagueEntryDTO {
+    /// Tier, division and league points together, comparable across entries. `None` for tiers
+    /// or divisions this crate doesn't know.
+    pub fn rank(&self) -> Option<Rank> {
+        Rank::new(self.tier.clone(), self.rank.clone(), self.league_points)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueItemDTO {
+    pub summoner_id: String,
+    pub summoner_name: String,
+    pub hot_streak: bool,
+    pub veteran: bool,
+    pub fresh_blood: bool,
+    pub inactive: bool,
+    pub rank: Division,
+    pub league_points: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueListDTO {
+    pub league_id: String,
+    pub tier: Tier,
+    pub entries: Vec<LeagueItemDTO>,
+    pub queue: Queue,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchlistDTO {
+    pub matches: Vec<MatchReferenceDTO>,
+    pub total_games: u32,
+    pub start_index: u32,
+    pub end_index: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchReferenceDTO {
+    pub game_id: u64,
+    pub role: Role,
+    pub season: u32,
+    pub platform_id: String,
+    pub champion: Champion,
+    pub queue: u32,
+    pub lane: Lane,
+    #[serde(with = "ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+impl MatchReferenceDTO {
+    /// Position played, when the lane and role are conclusive.
+    pub fn position(&self) -> Option<Position> {
+        Position::from_lane_role(self.lane.clone(), self.role.clone())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchDTO {
+    ///  Please refer to the Game Constants documentation.
+    pub season_id: u32,
+    /// Please refer to the Game Constants documentation.
+    pub queue_id: u64,
+    pub game_id: u64,
+    /// Participant identity information.
+    pub participant_identities: Vec<ParticipantIdentityDTO>,
+    /// The major.minor version typically indicates the patch the match was played on.
+    pub game_version: String,
+    /// Platform where the match was played.
+    pub platform_id: String,
+    /// Please refer to the Game Constants documentation.
+    pub game_mode: String,
+    /// Please refer to the Game Constants documentation.
+    pub map_id: u32,
+    /// Please refer to the Game Constants documentation.
+    pub game_type: String,
+    /// Team information.
+    pub teams: Vec<TeamStatsDTO>,
+    /// Participant information.
+    pub participants: Vec<ParticipantDTO>,
+    /// Match duration in seconds.
+    pub game_duration: u64,
+    /// Designates the timestamp when champion select ended and the loading screen appeared,
+    /// NOT when the game timer was at 0:00.
+    #[serde(with = "ts_milliseconds")]
+    pub game_creation: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantIdentityDTO {
+    /// Player information.
+    pub player: PlayerDTO,
+    pub participant_id: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerDTO {
+    pub current_platform_id: String,
+    pub summoner_name: String,
+    pub match_history_uri: String,
+    /// Original platformId.
+    pub platform_id: String,
+    /// Player's current accountId (Encrypted)
+    pub current_account_id: String,
+    pub profile_icon: u32,
+    /// Player's summonerId (Encrypted). Not included for older matches.
+    pub summoner_id: Option<String>,
+    /// Player's original accountId (Encrypted)
+    pub account_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantDTO {
+    /// Participant statistics.
+    pub stats: ParticipantStatsDTO,
+    pub participant_id: u32,
+    /// List of legacy Rune information. Not included for matches played with Runes Reforged.
+    pub runes: Option<Vec<RuneDTO>>,
+    /// Participant timeline data.
+    pub timeline: ParticipantTimelineDTO,
+    /// 100 for blue side. 200 for red side.
+    pub team_id: u8,
+    /// Second Summoner Spell id.
+    pub spell2_id: u32,
+    /// List of legacy Mastery information. Not included for matches played with Runes Reforged.
+    pub masteries: Option<Vec<MasteryDTO>>,
+    /// Highest ranked tier achieved for the previous season in a specific subset of queueIds, if
+    /// any, otherwise null. Used to display border in game loading screen. Please refer to the
+    /// Ranked Info documentation. `UNRANKED` is decoded as `None`.
+    #[serde(default, deserialize_with = "deserialize_ranked_tier")]
+    pub highest_achieved_season_tier: Option<Tier>,
+    /// First Summoner Spell id.
+    pub spell1_id: u32,
+    pub champion_id: Champion,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamStatsDTO {
+    /// Flag indicating whether or not the team scored the first Dragon kill.
+    pub first_dragon: bool,
+    /// Flag indicating whether or not the team destroyed the first inhibitor.
+    pub first_inhibitor: bool,
+    /// If match queueId has a draft, contains banned champion data, otherwise empty.
+    pub bans: Vec<TeamBansDTO>,
+    /// Number of times the team killed Baron.
+    pub baron_kills: u8,
+    /// Flag indicating whether or not the team scored the first Rift Herald kill.
+    pub first_rift_herald: bool,
+    /// Flag indicating whether or not the team scored the first Baron kill.
+    pub first_baron: bool,
+    /// Number of times the team killed Rift Herald.
+    pub rift_herald_kills: u8,
+    /// Flag indicating whether or not the team scored the first blood.
+    pub first_blood: bool,
+    /// 100 for blue side. 200 for red side.
+    pub team_id: u8,
+    /// Flag indicating whether or not the team destroyed the first tower.
+    pub first_tower: bool,
+    /// Number of times the team killed Vilemaw.
+    pub vilemaw_kills: u8,
+    /// Number of inhibitors the team destroyed.
+    pub inhibitor_kills: u8,
+    /// Number of towers the team destroyed.
+    pub tower_kills: u8,
+    /// For Dominion matches, specifies the points the team had at game end.
+    pub dominion_victory_score: Option<u32>,
+    /// String indicating whether or not the team won. There are only two values visibile in public
+    /// match history. (Legal values: Fail, Win)
+    pub win: String,
+    /// Number of times the team killed Dragon.
+    pub dragon_kills: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamBansDTO {
+    /// Turn during which the champion was banned.
+    pub pick_turn: u8,
+    /// Banned championId, -1 when the turn was skipped.
+    pub champion_id: Champion,
+}
+
+/// Rune fields are not included for matches played before Runes Reforged, nor objective fields for
+/// maps without nodes or altars.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantStatsDTO {
+    pub first_blood_assist: Option<bool>,
+    pub vision_score: u64,
+    pub magic_damage_dealt_to_champions: u64,
+    pub damage_dealt_to_objectives: u64,
+    pub total_time_crowd_control_dealt: u32,
+    pub longest_time_spent_living: u32,
+    /// Post game rune stats.
+    pub perk1_var1: Option<u32>,
+    /// Post game rune stats.
+    pub perk1_var3: Option<u32>,
+    /// Post game rune stats.
+    pub perk1_var2: Option<u32>,
+    pub triple_kills: u32,
+    /// Post game rune stats.
+    pub perk3_var3: Option<u32>,
+    pub node_neutralize_assist: Option<u32>,
+    /// Post game rune stats.
+    pub perk3_var2: Option<u32>,
+    pub player_score9: u32,
+    pub player_score8: u32,
+    pub kills: u32,
+    pub player_score1: u32,
+    pub player_score0: u32,
+    pub player_score3: u32,
+    pub player_score2: u32,
+    pub player_score5: u32,
+    pub player_score4: u32,
+    pub player_score7: u32,
+    pub player_score6: u32,
+    /// Post game rune stats.
+    pub perk5_var1: Option<u32>,
+    /// Post game rune stats.
+    pub perk5_var3: Option<u32>,
+    /// Post game rune stats.
+    pub perk5_var2: Option<u32>,
+    pub total_score_rank: u32,
+    pub neutral_minions_killed: u32,
+    pub damage_dealt_to_turrets: u64,
+    pub physical_damage_dealt_to_champions: u64,
+    pub node_capture: Option<u32>,
+    pub largest_multi_kill: u32,
+    /// Post game rune stats.
+    pub perk2_var2: Option<u32>,
+    /// Post game rune stats.
+    pub perk2_var3: Option<u32>,
+    pub total_units_healed: u32,
+    /// Post game rune stats.
+    pub perk2_var1: Option<u32>,
+    /// Post game rune stats.
+    pub perk4_var1: Option<u32>,
+    /// Post game rune stats.
+    pub perk4_var2: Option<u32>,
+    /// Post game rune stats.
+    pub perk4_var3: Option<u32>,
+    pub wards_killed: u32,
+    pub largest_critical_strike: u32,
+    pub largest_killing_spree: u32,
+    pub quadra_kills: u32,
+    pub team_objective: Option<u32>,
+    pub magic_damage_dealt: u64,
+    pub item2: u32,
+    pub item3: u32,
+    pub item0: u32,
+    pub neutral_minions_killed_team_jungle: u32,
+    pub item6: u32,
+    pub item4: u32,
+    pub item5: u32,
+    /// Primary path rune.
+    pub perk1: Option<u32>,
+    /// Primary path keystone rune.
+    pub perk0: Option<u32>,
+    /// Primary path rune.
+    pub perk3: Option<u32>,
+    /// Primary path rune.
+    pub perk2: Option<u32>,
+    /// Secondary path rune.
+    pub perk5: Option<u32>,
+    /// Secondary path rune.
+    pub perk4: Option<u32>,
+    /// Post game rune stats.
+    pub perk3_var1: Option<u32>,
+    pub damage_self_mitigated: u64,
+    pub magical_damage_taken: u64,
+    pub first_inhibitor_kill: Option<bool>,
+    pub true_damage_taken: u64,
+    pub node_neutralize: Option<u32>,
+    pub assists: u32,
+    pub combat_player_score: u32,
+    /// Primary rune path
+    pub perk_primary_style: Option<u32>,
+    pub gold_spent: u32,
+    pub true_damage_dealt: u64,
+    pub participant_id: u32,
+    pub total_damage_taken: u64,
+    pub physical_damage_dealt: u64,
+    pub sight_wards_bought_in_game: u32,
+    pub total_damage_dealt_to_champions: u64,
+    pub physical_damage_taken: u64,
+    pub total_player_score: u32,
+    pub win: bool,
+    pub objective_player_score: u32,
+    pub total_damage_dealt: u64,
+    pub item1: u32,
+    pub neutral_minions_killed_enemy_jungle: u32,
+    pub deaths: u32,
+    pub wards_placed: u32,
+    /// Secondary rune path
+    pub perk_sub_style: Option<u32>,
+    pub turret_kills: u32,
+    pub first_blood_kill: Option<bool>,
+    pub true_damage_dealt_to_champions: u64,
+    pub gold_earned: u32,
+    pub killing_sprees: u32,
+    pub unreal_kills: u32,
+    pub altars_captured: Option<u32>,
+    pub first_tower_assist: Option<bool>,
+    pub first_tower_kill: Option<bool>,
+    pub champ_level: u32,
+    pub double_kills: u32,
+    pub node_capture_assist: Option<u32>,
+    pub inhibitor_kills: u32,
+    pub first_inhibitor_assist: Option<bool>,
+    /// Post game rune stats.
+    pub perk0_var1: Option<u32>,
+    /// Post game rune stats.
+    pub perk0_var2: Option<u32>,
+    /// Post game rune stats.
+    pub perk0_var3: Option<u32>,
+    pub vision_wards_bought_in_game: u32,
+    pub altars_neutralized: Option<u32>,
+    pub penta_kills: u32,
+    pub total_heal: u64,
+    pub total_minions_killed: u32,
+    #[serde(rename = "timeCCingOthers")]
+    pub time_c_c_ing_others: u64,
+    /// Offense stat rune.
+    pub stat_perk0: Option<u32>,
+    /// Flex stat rune.
+    pub stat_perk1: Option<u32>,
+    /// Defense stat rune.
+    pub stat_perk2: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneDTO {
+    pub rune_id: u32,
+    pub rank: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantTimelineDTO {
+    /// Participant's calculated lane.
+    pub lane: Lane,
+    pub participant_id: u32,
+    /// Creep score difference versus the calculated lane opponent(s) for a specified period.
+    #[serde(default)]
+    pub cs_diff_per_min_deltas: Deltas,
+    /// Gold for a specified period.
+    #[serde(default)]
+    pub gold_per_min_deltas: Deltas,
+    /// Experience difference versus the calculated lane opponent(s) for a specified period.
+    #[serde(default)]
+    pub xp_diff_per_min_deltas: Deltas,
+    /// Creeps for a specified period.
+    #[serde(default)]
+    pub creeps_per_min_deltas: Deltas,
+    /// Experience change for a specified period.
+    #[serde(default)]
+    pub xp_per_min_deltas: Deltas,
+    /// Participant's calculated role.
+    pub role: Role,
+    /// Damage taken difference versus the calculated lane opponent(s) for a specified period.
+    #[serde(default)]
+    pub damage_taken_diff_per_min_deltas: Deltas,
+    /// Damage taken for a specified period.
+    #[serde(default)]
+    pub damage_taken_per_min_deltas: Deltas,
+}
+
+impl ParticipantTimelineDTO {
+    /// Position played, when the lane and role are conclusive.
+    pub fn position(&self) -> Option<Position> {
+        Position::from_lane_role(self.lane.clone(), self.role.clone())
+    }
+}
+
+/// Game period of a timeline delta.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DeltaWindow {
+    #[serde(rename = "0-10")]
+    ZeroToTen,
+    #[serde(rename = "10-20")]
+    TenToTwenty,
+    #[serde(rename = "20-30")]
+    TwentyToThirty,
+    #[serde(rename = "30-end")]
+    ThirtyToEnd,
+}
+
+/// Per minute values of a participant statistic, for each period the game lasted.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(transparent)]
+pub struct Deltas(BTreeMap<DeltaWindow, f32>);
+
+impl Deltas {
+    /// Value for the given period, if the game lasted until then.
+    pub fn get(&self, window: DeltaWindow) -> Option<f32> {
+        self.0.get(&window).cloned()
+    }
+
+    /// Periods and their values, from the start of the game.
+    pub fn iter(&self) -> impl Iterator<Item = (DeltaWindow, f32)> + '_ {
+        self.0.iter().map(|(window, value)| (*window, *value))
+    }
+
+    /// Values from the start of the game.
+    pub fn values(&self) -> impl Iterator<Item = f32> + '_ {
+        self.0.values().cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MasteryDTO {
+    pub mastery_id: u32,
+    pub rank: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchTimelineDTO {
+    pub frames: Vec<MatchFrameDTO>,
+    pub frame_interval: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchFrameDTO {
+    pub timestamp: u64,
+    /// Frames keyed by participant id.
+    pub participant_frames: BTreeMap<u32, MatchParticipantFrameDTO>,
+    pub events: Vec<MatchEvent>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchParticipantFrameDTO {
+    pub total_gold: u32,
+    pub team_score: u32,
+    pub participant_id: u32,
+    pub level: u32,
+    pub current_gold: u32,
+    pub minions_killed: u32,
+    pub dominion_score: u32,
+    /// Not included in the last frame of the timeline.
+    pub position: Option<MatchPositionDTO>,
+    pub xp: u32,
+    pub jungle_minions_killed: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchPositionDTO {
+    pub y: u32,
+    pub x: u32,
+}
+
+/// Event of a match timeline. Each kind of event only holds its own fields, events of an unknown
+/// kind are decoded as `Unknown`.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MatchEvent {
+    #[serde(rename_all = "camelCase")]
+    ChampionKill {
+        timestamp: u64,
+        position: MatchPositionDTO,
+        killer_id: u32,
+        victim_id: u32,
+        #[serde(default)]
+        assisting_participant_ids: Vec<u32>,
+    },
+    #[serde(rename_all = "camelCase")]
+    WardPlaced {
+        timestamp: u64,
+        ward_type: String,
+        creator_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    WardKill {
+        timestamp: u64,
+        ward_type: String,
+        killer_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    BuildingKill {
+        timestamp: u64,
+        position: MatchPositionDTO,
+        killer_id: u32,
+        #[serde(default)]
+        assisting_participant_ids: Vec<u32>,
+        /// Team owning the building.
+        team_id: u32,
+        /// (Legal values: INHIBITOR_BUILDING, TOWER_BUILDING)
+        building_type: String,
+        /// (Legal values: BOT_LANE, MID_LANE, TOP_LANE)
+        lane_type: String,
+        /// (Legal values: BASE_TURRET, FOUNTAIN_TURRET, INNER_TURRET, NEXUS_TURRET, OUTER_TURRET,
+        /// UNDEFINED_TURRET)
+        tower_type: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    EliteMonsterKill {
+        timestamp: u64,
+        position: MatchPositionDTO,
+        killer_id: u32,
+        /// (Legal values: BARON_NASHOR, DRAGON, RIFTHERALD)
+        monster_type: String,
+        /// Kind of dragon, for `DRAGON` kills.
+        monster_sub_type: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemPurchased {
+        timestamp: u64,
+        participant_id: u32,
+        item_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemSold {
+        timestamp: u64,
+        participant_id: u32,
+        item_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemDestroyed {
+        timestamp: u64,
+        participant_id: u32,
+        item_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemUndo {
+        timestamp: u64,
+        participant_id: u32,
+        /// Item given back, 0 when undoing a sale.
+        before_id: u32,
+        /// Item bought back, 0 when undoing a purchase.
+        after_id: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    SkillLevelUp {
+        timestamp: u64,
+        participant_id: u32,
+        skill_slot: u32,
+        /// (Legal values: EVOLVE, NORMAL)
+        level_up_type: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    AscendedEvent {
+        timestamp: u64,
+        position: Option<MatchPositionDTO>,
+        killer_id: u32,
+        /// (Legal values: CHAMPION_ASCENDED, CLEAR_ASCENDED, MINION_ASCENDED)
+        ascended_type: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    CapturePoint {
+        timestamp: u64,
+        position: Option<MatchPositionDTO>,
+        killer_id: u32,
+        /// (Legal values: POINT_A, POINT_B, POINT_C, POINT_D, POINT_E)
+        point_captured: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PoroKingSummon {
+        timestamp: u64,
+        team_id: Option<u32>,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// Decodes a tier where Riot sends `UNRANKED` for players without one.
+fn deserialize_ranked_tier<'de, D>(deserializer: D) -> Result<Option<Tier>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(ref tier) if tier != "UNRANKED" => parse_or_unknown(tier, Tier::Unknown).map(Some),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_champion_masteries_by_puuid() {
+        let mastery: ChampionMasteryDTO = serde_json::from_str(
+            r#"{
+                "puuid": "some-puuid",
+                "championId": 103,
+                "championLevel": 7,
+                "championPoints": 245112,
+                "lastPlayTime": 1625000000000,
+                "championPointsSinceLastLevel": 223512,
+                "championPointsUntilNextLevel": 0,
+                "chestGranted": true,
+                "tokensEarned": 0
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(mastery.champion_points, 245_112);
+        assert_eq!(mastery.puuid.as_deref(), Some("some-puuid"));
+        assert!(mastery.summoner_id.is_none());
+    }
+
+    #[test]
+    fn deserializes_timeline_deltas_in_order() {
+        let timeline: ParticipantTimelineDTO = serde_json::from_str(
+            r#"{
+                "lane": "MIDDLE",
+                "participantId": 4,
+                "role": "SOLO",
+                "goldPerMinDeltas": {"20-30": 412.5, "0-10": 250.1, "30-end": 501.0, "10-20": 380.2},
+                "xpPerMinDeltas": {"0-10": 410.4}
+            }"#,
+        )
+        .unwrap();
+
+        let gold = &timeline.gold_per_min_deltas;
+        assert_eq!(
+            gold.iter().map(|(window, _)| window).collect::<Vec<_>>(),
+            vec![
+                DeltaWindow::ZeroToTen,
+                DeltaWindow::TenToTwenty,
+                DeltaWindow::TwentyToThirty,
+                DeltaWindow::ThirtyToEnd
+            ]
+        );
+        assert_eq!(
+            gold.values().collect::<Vec<_>>(),
+            vec![250.1, 380.2, 412.5, 501.0]
+        );
+        assert_eq!(
+            timeline.xp_per_min_deltas.get(DeltaWindow::ZeroToTen),
+            Some(410.4)
+        );
+        assert_eq!(
+            timeline.xp_per_min_deltas.get(DeltaWindow::TenToTwenty),
+            None
+        );
+        assert!(timeline.cs_diff_per_min_deltas.is_empty());
+    }
+
+    #[test]
+    fn deserializes_participant_frames_by_id() {
+        let frame: MatchFrameDTO = serde_json::from_str(
+            r#"{
+                "timestamp": 60000,
+                "participantFrames": {
+                    "2": {"totalGold": 600, "teamScore": 0, "participantId": 2, "level": 1,
+                          "currentGold": 100, "minionsKilled": 3, "dominionScore": 0,
+                          "position": {"x": 1200, "y": 900}, "xp": 280, "jungleMinionsKilled": 0},
+                    "1": {"totalGold": 500, "teamScore": 0, "participantId": 1, "level": 1,
+                          "currentGold": 0, "minionsKilled": 0, "dominionScore": 0,
+                          "position": {"x": 560, "y": 580}, "xp": 0, "jungleMinionsKilled": 4}
+                },
+                "events": []
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            frame.participant_frames.keys().collect::<Vec<_>>(),
+            vec![&1, &2]
+        );
+        assert_eq!(frame.participant_frames[&2].total_gold, 600);
+        assert_eq!(frame.participant_frames[&1].jungle_minions_killed, 4);
+    }
+
+    #[test]
+    fn deserializes_tagged_match_events() {
+        let events: Vec<MatchEvent> = serde_json::from_str(
+            r#"[
+                {"type": "CHAMPION_KILL", "timestamp": 431234, "position": {"x": 7000, "y": 7100},
+                 "killerId": 3, "victimId": 8, "assistingParticipantIds": [1, 4]},
+                {"type": "ITEM_PURCHASED", "timestamp": 2400, "participantId": 5, "itemId": 1055},
+                {"type": "ELITE_MONSTER_KILL", "timestamp": 601000, "position": {"x": 9866, "y": 4414},
+                 "killerId": 2, "monsterType": "DRAGON", "monsterSubType": "FIRE_DRAGON"},
+                {"type": "SKILL_LEVEL_UP", "timestamp": 1500, "participantId": 1, "skillSlot": 3,
+                 "levelUpType": "NORMAL"},
+                {"type": "SOMETHING_NEW", "timestamp": 1000, "someField": true}
+            ]"#,
+        )
+        .unwrap();
+
+        match &events[0] {
+            MatchEvent::ChampionKill {
+                killer_id,
+                victim_id,
+                assisting_participant_ids,
+                ..
+            } => {
+                assert_eq!((*killer_id, *victim_id), (3, 8));
+                assert_eq!(assisting_participant_ids, &vec![1, 4]);
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+        match &events[1] {
+            MatchEvent::ItemPurchased { item_id, .. } => assert_eq!(*item_id, 1055),
+            event => panic!("unexpected event: {:?}", event),
+        }
+        match &events[2] {
+            MatchEvent::EliteMonsterKill {
+                monster_sub_type, ..
+            } => assert_eq!(monster_sub_type.as_deref(), Some("FIRE_DRAGON")),
+            event => panic!("unexpected event: {:?}", event),
+        }
+        match &events[3] {
+            MatchEvent::SkillLevelUp { skill_slot, .. } => assert_eq!(*skill_slot, 3),
+            event => panic!("unexpected event: {:?}", event),
+        }
+        match &events[4] {
+            MatchEvent::Unknown => {}
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+}
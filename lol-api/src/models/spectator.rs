@@ -0,0 +1,156 @@
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::constants::Champion;
+
+/// Game a summoner is currently playing.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentGameInfo {
+    /// The ID of the game.
+    pub game_id: u64,
+    /// The game type.
+    pub game_type: String,
+    /// The game start time represented in epoch milliseconds. Zero while players are still
+    /// loading.
+    #[serde(with = "ts_milliseconds")]
+    pub game_start_time: DateTime<Utc>,
+    /// The ID of the map.
+    pub map_id: u64,
+    /// The amount of time in seconds that has passed since the game started.
+    pub game_length: i64,
+    /// The ID of the platform on which the game is being played.
+    pub platform_id: String,
+    /// The game mode.
+    pub game_mode: String,
+    /// Banned champion information.
+    pub banned_champions: Vec<BannedChampion>,
+    /// The queue type (queue types are documented on the Game Constants page). Not included for
+    /// custom games.
+    pub game_queue_config_id: Option<u64>,
+    /// The observer information.
+    pub observers: Observer,
+    /// The participant information.
+    pub participants: Vec<CurrentGameParticipant>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BannedChampion {
+    /// The turn during which the champion was banned.
+    pub pick_turn: u32,
+    /// The ID of the banned champion. `-1` when no champion was banned.
+    pub champion_id: Champion,
+    /// The ID of the team that banned the champion.
+    pub team_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Observer {
+    /// Key used to decrypt the spectator grid game data for playback.
+    pub encryption_key: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentGameParticipant {
+    /// The ID of the champion played by this participant.
+    pub champion_id: Champion,
+    /// Perks/Runes Reforged Information.
+    pub perks: Perks,
+    /// The ID of the profile icon used by this participant.
+    pub profile_icon_id: u64,
+    /// Flag indicating whether or not this participant is a bot.
+    pub bot: bool,
+    /// The team ID of this participant, indicating the participant's team.
+    pub team_id: u64,
+    /// The summoner name of this participant.
+    pub summoner_name: String,
+    /// The encrypted summoner ID of this participant.
+    pub summoner_id: String,
+    /// The ID of the first summoner spell used by this participant.
+    pub spell1_id: u64,
+    /// The ID of the second summoner spell used by this participant.
+    pub spell2_id: u64,
+    /// List of Game Customizations.
+    pub game_customization_objects: Vec<GameCustomizationObject>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Perks {
+    /// IDs of the perks/runes assigned.
+    pub perk_ids: Vec<u64>,
+    /// Primary runes path.
+    pub perk_style: u64,
+    /// Secondary runes path.
+    pub perk_sub_style: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GameCustomizationObject {
+    /// Category identifier for Game Customization.
+    pub category: String,
+    /// Game Customization content.
+    pub content: String,
+}
+
+/// List of games currently featured in the client.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedGames {
+    /// The list of featured games.
+    pub game_list: Vec<FeaturedGameInfo>,
+    /// The suggested interval to wait before requesting FeaturedGames again, in seconds.
+    pub client_refresh_interval: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedGameInfo {
+    /// The game mode.
+    pub game_mode: String,
+    /// The amount of time in seconds that has passed since the game started.
+    pub game_length: i64,
+    /// The ID of the map.
+    pub map_id: u64,
+    /// The game type.
+    pub game_type: String,
+    /// Banned champion information.
+    pub banned_champions: Vec<BannedChampion>,
+    /// The ID of the game.
+    pub game_id: u64,
+    /// The observer information.
+    pub observers: Observer,
+    /// The queue type (queue types are documented on the Game Constants page).
+    pub game_queue_config_id: u64,
+    /// The game start time represented in epoch milliseconds.
+    #[serde(with = "ts_milliseconds")]
+    pub game_start_time: DateTime<Utc>,
+    /// The participant information.
+    pub participants: Vec<FeaturedGameParticipant>,
+    /// The ID of the platform on which the game is being played.
+    pub platform_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedGameParticipant {
+    /// Flag indicating whether or not this participant is a bot.
+    pub bot: bool,
+    /// The ID of the second summoner spell used by this participant.
+    pub spell2_id: u64,
+    /// The ID of the profile icon used by this participant.
+    pub profile_icon_id: u64,
+    /// The summoner name of this participant.
+    pub summoner_name: String,
+    /// The ID of the champion played by this participant.
+    pub champion_id: Champion,
+    /// The team ID of this participant, indicating the participant's team.
+    pub team_id: u64,
+    /// The ID of the first summoner spell used by this participant.
+    pub spell1_id: u64,
+}
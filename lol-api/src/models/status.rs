@@ -0,0 +1,196 @@
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Status of a platform, including its ongoing incidents and maintenances.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PlatformDataDTO {
+    pub id: String,
+    pub name: String,
+    pub locales: Vec<String>,
+    pub maintenances: Vec<StatusDTO>,
+    pub incidents: Vec<StatusDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StatusDTO {
+    pub id: u64,
+    /// Only present for maintenances.
+    pub maintenance_status: Option<MaintenanceStatus>,
+    /// Only present for incidents.
+    pub incident_severity: Option<IncidentSeverity>,
+    pub titles: Vec<ContentDTO>,
+    pub updates: Vec<UpdateDTO>,
+    pub created_at: DateTime<Utc>,
+    pub archive_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub platforms: Vec<StatusPlatform>,
+}
+
+impl StatusDTO {
+    /// Title in the given locale, like `en_US`.
+    pub fn title(&self, locale: &str) -> Option<&str> {
+        find_locale(&self.titles, locale)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ContentDTO {
+    pub locale: String,
+    pub content: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateDTO {
+    pub id: u64,
+    pub author: String,
+    pub publish: bool,
+    pub publish_locations: Vec<PublishLocation>,
+    pub translations: Vec<ContentDTO>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl UpdateDTO {
+    /// Update content in the given locale, like `en_US`.
+    pub fn translation(&self, locale: &str) -> Option<&str> {
+        find_locale(&self.translations, locale)
+    }
+}
+
+fn find_locale<'a>(contents: &'a [ContentDTO], locale: &str) -> Option<&'a str> {
+    contents
+        .iter()
+        .find(|content| content.locale == locale)
+        .map(|content| content.content.as_ref())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceStatus {
+    Scheduled,
+    InProgress,
+    Complete,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Severity of an incident, from lowest to highest. Unknown severities can't be ranked, so they
+/// sort below every known one.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentSeverity {
+    Info,
+    Warning,
+    Critical,
+    #[serde(other)]
+    Unknown,
+}
+
+impl IncidentSeverity {
+    fn level(self) -> u8 {
+        match self {
+            IncidentSeverity::Unknown => 0,
+            IncidentSeverity::Info => 1,
+            IncidentSeverity::Warning => 2,
+            IncidentSeverity::Critical => 3,
+        }
+    }
+}
+
+impl Ord for IncidentSeverity {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.level().cmp(&other.level())
+    }
+}
+
+impl PartialOrd for IncidentSeverity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Where an update is published.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PublishLocation {
+    RiotClient,
+    RiotStatus,
+    Game,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Device platforms affected by an incident or maintenance.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusPlatform {
+    Windows,
+    MacOS,
+    Android,
+    IOS,
+    PS4,
+    XBone,
+    Switch,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_localised_status() {
+        let status: StatusDTO = serde_json::from_str(
+            r#"{
+                "id": 4012,
+                "maintenance_status": null,
+                "incident_severity": "warning",
+                "titles": [
+                    { "locale": "en_US", "content": "Ranked queues disabled" },
+                    { "locale": "es_ES", "content": "Colas clasificatorias desactivadas" }
+                ],
+                "updates": [{
+                    "id": 7001,
+                    "author": "",
+                    "publish": true,
+                    "publish_locations": ["riotclient", "riotstatus", "game"],
+                    "translations": [{ "locale": "en_US", "content": "We are investigating." }],
+                    "created_at": "2020-02-11T18:32:10.000Z",
+                    "updated_at": "2020-02-11T18:32:10.000Z"
+                }],
+                "created_at": "2020-02-11T18:32:10.000Z",
+                "archive_at": null,
+                "updated_at": null,
+                "platforms": ["windows", "macos", "smart_fridge"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(status.incident_severity, Some(IncidentSeverity::Warning));
+        assert!(IncidentSeverity::Unknown < IncidentSeverity::Info);
+        assert!(IncidentSeverity::Warning < IncidentSeverity::Critical);
+        assert_eq!(
+            status.title("es_ES"),
+            Some("Colas clasificatorias desactivadas")
+        );
+        assert_eq!(status.title("ko_KR"), None);
+        assert_eq!(
+            status.updates[0].publish_locations,
+            vec![
+                PublishLocation::RiotClient,
+                PublishLocation::RiotStatus,
+                PublishLocation::Game
+            ]
+        );
+        assert_eq!(
+            status.platforms,
+            vec![
+                StatusPlatform::Windows,
+                StatusPlatform::MacOS,
+                StatusPlatform::Unknown
+            ]
+        );
+    }
+}
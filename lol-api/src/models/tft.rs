@@ -0,0 +1,234 @@
+//! Teamfight Tactics DTOs. TFT summoners reuse `SummonerDTO` and apex leagues reuse
+//! `LeagueListDTO`.
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::constants::{Division, Queue, Rank, Tier};
+
+/// League entry of a TFT player. Hyper Roll entries only have a rated tier and rating.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueEntryDTO {
+    /// Not included for the Hyper Roll queue.
+    pub league_id: Option<String>,
+    pub puuid: Option<String>,
+    pub summoner_id: String,
+    pub summoner_name: Option<String>,
+    pub queue_type: Queue,
+    /// Only included for the Hyper Roll queue, like `ORANGE`.
+    pub rated_tier: Option<String>,
+    /// Only included for the Hyper Roll queue.
+    pub rated_rating: Option<u32>,
+    /// Not included for the Hyper Roll queue.
+    pub tier: Option<Tier>,
+    /// The player's division within a tier. Not included for the Hyper Roll queue.
+    pub rank: Option<Division>,
+    /// Not included for the Hyper Roll queue.
+    pub league_points: Option<u32>,
+    /// First placement.
+    pub wins: u32,
+    /// Second through eighth placement.
+    pub losses: u32,
+    /// Not included for the Hyper Roll queue.
+    #[serde(default)]
+    pub hot_streak: bool,
+    #[serde(default)]
+    pub veteran: bool,
+    #[serde(default)]
+    pub fresh_blood: bool,
+    #[serde(default)]
+    pub inactive: bool,
+}
+
+impl LeagueEntryDTO {
+    /// Tier, division and league points together. Not included for the Hyper Roll queue, nor
+    /// for tiers or divisions this crate doesn't know.
+    pub fn rank(&self) -> Option<Rank> {
+        match (&self.tier, &self.rank, self.league_points) {
+            (Some(tier), Some(division), Some(lp)) => Rank::new(tier.clone(), division.clone(), lp),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MatchDTO {
+    /// Match metadata.
+    pub metadata: MetadataDTO,
+    /// Match info.
+    pub info: InfoDTO,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MetadataDTO {
+    /// Match data version.
+    pub data_version: String,
+    /// Match id, like `EUW1_123`.
+    pub match_id: String,
+    /// A list of participant PUUIDs.
+    pub participants: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InfoDTO {
+    /// Unix timestamp in milliseconds.
+    pub game_datetime: i64,
+    /// Game length in seconds.
+    pub game_length: f32,
+    /// Game variation key. Game variations are documented in TFT static data.
+    pub game_variation: Option<String>,
+    /// Game client version.
+    pub game_version: String,
+    pub participants: Vec<ParticipantDTO>,
+    /// Please refer to the League of Legends documentation.
+    pub queue_id: u32,
+    /// Teamfight Tactics set number.
+    pub tft_set_number: u32,
+    /// Teamfight Tactics set name, like `TFTSet9_2`.
+    pub tft_set_core_name: Option<String>,
+    /// Like `standard` or `pairs`.
+    pub tft_game_type: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ParticipantDTO {
+    /// Augments picked by the participant, in order.
+    #[serde(default)]
+    pub augments: Vec<String>,
+    /// Participant's companion.
+    pub companion: CompanionDTO,
+    /// Gold left after participant was eliminated.
+    pub gold_left: u32,
+    /// The round the participant was eliminated in. Note: If the player was eliminated in stage
+    /// 2-1 their last_round would be 5.
+    pub last_round: u32,
+    /// Participant Little Legend level. Note: This is not the number of active units.
+    pub level: u32,
+    /// Participant placement upon elimination.
+    pub placement: u32,
+    /// Number of players the participant eliminated.
+    pub players_eliminated: u32,
+    pub puuid: String,
+    /// The number of seconds before the participant was eliminated.
+    pub time_eliminated: f32,
+    /// Damage the participant dealt to other players.
+    pub total_damage_to_players: u32,
+    /// A complete list of traits for the participant's active units.
+    pub traits: Vec<TraitDTO>,
+    /// A list of active units for the participant.
+    pub units: Vec<UnitDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CompanionDTO {
+    #[serde(rename = "content_ID")]
+    pub content_id: String,
+    #[serde(rename = "item_ID")]
+    pub item_id: u32,
+    #[serde(rename = "skin_ID")]
+    pub skin_id: u32,
+    pub species: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TraitDTO {
+    /// Trait name.
+    pub name: String,
+    /// Number of units with this trait.
+    pub num_units: u32,
+    /// Current style for this trait. (0 = No style, 1 = Bronze, 2 = Silver, 3 = Gold,
+    /// 4 = Chromatic)
+    pub style: u32,
+    /// Current active tier for the trait.
+    pub tier_current: u32,
+    /// Total tiers for the trait.
+    pub tier_total: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UnitDTO {
+    /// A list of the unit's item ids. Replaced by `item_names` in later sets.
+    pub items: Option<Vec<u32>>,
+    /// A list of the unit's item names.
+    #[serde(rename = "itemNames")]
+    pub item_names: Option<Vec<String>>,
+    /// This field was introduced in patch 9.22 with data_version 2.
+    pub character_id: String,
+    /// If a unit is chosen as part of the Fates set mechanic, the chosen trait will be indicated
+    /// by this field. Otherwise this field is excluded from the response.
+    pub chosen: Option<String>,
+    /// Unit name. This field is often left blank.
+    pub name: String,
+    /// Unit rarity. This doesn't equate to the unit cost.
+    pub rarity: u32,
+    /// Unit tier.
+    pub tier: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_participants() {
+        let participant: ParticipantDTO = serde_json::from_str(
+            r#"{
+                "augments": ["TFT9_Augment_Commander_TeamingUp"],
+                "companion": {
+                    "content_ID": "1cd1ab0d-1d0c-4c3b-9a23-5bdea6d94b4c",
+                    "item_ID": 23,
+                    "skin_ID": 23,
+                    "species": "PetDowsie"
+                },
+                "gold_left": 2,
+                "last_round": 33,
+                "level": 8,
+                "placement": 1,
+                "players_eliminated": 2,
+                "puuid": "some-puuid",
+                "time_eliminated": 2061.5,
+                "total_damage_to_players": 140,
+                "traits": [
+                    {"name": "Set9_Bruiser", "num_units": 2, "style": 1, "tier_current": 1, "tier_total": 3}
+                ],
+                "units": [
+                    {
+                        "character_id": "TFT9_Sett",
+                        "itemNames": ["TFT_Item_WarmogsArmor"],
+                        "name": "",
+                        "rarity": 4,
+                        "tier": 2
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(participant.companion.item_id, 23);
+        assert_eq!(participant.traits[0].num_units, 2);
+        assert_eq!(
+            participant.units[0].item_names,
+            Some(vec![String::from("TFT_Item_WarmogsArmor")])
+        );
+        assert!(participant.units[0].items.is_none());
+    }
+
+    #[test]
+    fn deserializes_hyper_roll_entries() {
+        let entry: LeagueEntryDTO = serde_json::from_str(
+            r#"{
+                "summonerId": "some-id",
+                "queueType": "RANKED_TFT_TURBO",
+                "ratedTier": "ORANGE",
+                "ratedRating": 6000,
+                "wins": 10,
+                "losses": 40
+            }"#,
+        )
+        .unwrap();
+
+        assert!(entry.tier.is_none());
+        assert_eq!(entry.queue_type, Queue::RankedTftTurbo);
+        assert_eq!(entry.rated_rating, Some(6000));
+    }
+}
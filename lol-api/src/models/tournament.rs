@@ -0,0 +1,173 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCodeParameters {
+    /// Optional list of encrypted summoner IDs in order to validate the players eligible to join
+    /// the lobby. NOTE: We currently do not enforce participants at the team level, but rather the
+    /// aggregate of teamOne and teamTwo. We may add the ability to enforce at the team level in the
+    /// future.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_summoner_ids: Option<Vec<String>>,
+    /// Optional string that may contain any data in any format, if specified at all. Used to
+    /// denote any custom information about the game.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    /// The team size of the game. Valid values are 1-5.
+    pub team_size: u32,
+    pub pick_type: PickType,
+    pub map_type: MapType,
+    pub spectator_type: SpectatorType,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCodeDTO {
+    /// The tournament code.
+    pub code: String,
+    /// The spectator mode for the tournament code game.
+    pub spectators: SpectatorType,
+    /// The lobby name for the tournament code game.
+    pub lobby_name: String,
+    /// The metadata for tournament code.
+    pub meta_data: String,
+    /// The password for the tournament code game.
+    pub password: String,
+    /// The team size for the tournament code game.
+    pub team_size: u32,
+    /// The provider's ID.
+    pub provider_id: i32,
+    /// The pick mode for tournament code game.
+    pub pick_type: PickType,
+    /// The tournament's ID.
+    pub tournament_id: i32,
+    /// The tournament code's ID.
+    pub id: i32,
+    /// The tournament code's region.
+    pub region: TournamentRegion,
+    /// The game map for the tournament code game.
+    pub map: MapType,
+    /// The summonerIds of the participants (Encrypted)
+    pub participants: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCodeUpdateParameters {
+    /// Optional list of encrypted summoner IDs in order to validate the players eligible to join
+    /// the lobby.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_summoner_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pick_type: Option<PickType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub map_type: Option<MapType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spectator_type: Option<SpectatorType>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LobbyEventDTOWrapper {
+    pub event_list: Vec<LobbyEventDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LobbyEventDTO {
+    /// The summonerId that triggered the event (Encrypted). Not included for lobby wide events,
+    /// like `PracticeGameCreatedEvent`.
+    pub summoner_id: Option<String>,
+    /// The type of event that was triggered
+    pub event_type: String,
+    /// Timestamp from the event
+    pub timestamp: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderRegistrationParameters {
+    /// The region in which the provider will be running tournaments.
+    pub region: TournamentRegion,
+    /// The provider's callback URL to which tournament game results in this region should be
+    /// posted. The URL must be well-formed, use the http or https protocol, and use the default
+    /// port for the protocol (http URLs must use port 80, https URLs must use port 443).
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentRegistrationParameters {
+    /// The provider ID to specify the regional registered provider data to associate this
+    /// tournament.
+    pub provider_id: i32,
+    /// The optional name of the tournament.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PickType {
+    BlindPick,
+    DraftMode,
+    AllRandom,
+    TournamentDraft,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MapType {
+    SummonersRift,
+    TwistedTreeline,
+    HowlingAbyss,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpectatorType {
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "LOBBYONLY")]
+    LobbyOnly,
+    #[serde(rename = "ALL")]
+    All,
+}
+
+/// Regions tournament providers can be registered in.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TournamentRegion {
+    Br,
+    Eune,
+    Euw,
+    Jp,
+    Lan,
+    Las,
+    Na,
+    Oce,
+    Pbe,
+    Ru,
+    Tr,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_code_parameters() {
+        let parameters = TournamentCodeParameters {
+            allowed_summoner_ids: None,
+            metadata: Some(String::from("week 1")),
+            team_size: 5,
+            pick_type: PickType::TournamentDraft,
+            map_type: MapType::SummonersRift,
+            spectator_type: SpectatorType::LobbyOnly,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&parameters).unwrap(),
+            r#"{"metadata":"week 1","teamSize":5,"pickType":"TOURNAMENT_DRAFT","mapType":"SUMMONERS_RIFT","spectatorType":"LOBBYONLY"}"#
+        );
+    }
+}
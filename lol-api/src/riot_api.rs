@@ -1,16 +1,26 @@
 use failure::Error;
 use futures::future;
-use hyper::client::HttpConnector;
-use hyper::header::HeaderValue;
+use hyper::header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use hyper::http::StatusCode;
 use hyper::rt::{Future, Stream};
 use hyper::{Body, Client, Method, Request, Uri};
-use hyper_tls::HttpsConnector;
-use log::debug;
+use log::{debug, info};
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::fmt::Debug;
+use std::sync::Arc;
+use url::form_urlencoded;
 
+use crate::api_key::{ApiKey, KeySource};
+use crate::connector::{build_connector, Connector, SCHEME};
 use crate::constants::WithHost;
-use crate::endpoints::{ChampionMasteryV4, ChampionV3, LeagueV4, MatchV4, SummonerV4};
+use crate::endpoints::routes::{Auth, Route};
+use crate::endpoints::{
+    AccountV1, ChallengesV1, ChampionMasteryV4, ChampionV3, ClashV1, LeagueExpV4, LeagueV4,
+    LolStatusV4, MatchV4, MatchV5, SpectatorV4, SummonerV4, TftLeagueV1, TftMatchV1, TftSummonerV1,
+    ThirdPartyCodeV4, TournamentStubV4, TournamentV4,
+};
+use crate::rso::TokenProvider;
 use crate::FetchError;
 
 pub struct RiotApi {
@@ -25,138 +35,530 @@ impl RiotApi {
     /// Handle for SummonerV4
     ///
     /// <a href="https://developer.riotgames.com/apis#summoner-v4">Official API Documentation</a>
-    pub fn summoner_v4(&self) -> SummonerV4 {
+    pub fn summoner_v4(&self) -> SummonerV4<'_> {
         SummonerV4::new(self)
     }
 
     /// Handle for ChampionV3 endpoints.
     ///
     /// <a href="https://developer.riotgames.com/apis#champion-v3">Official API Documentation</a>
-    pub fn champion_v3(&self) -> ChampionV3 {
+    pub fn champion_v3(&self) -> ChampionV3<'_> {
         ChampionV3::new(self)
     }
 
+    /// Handle for ChallengesV1 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#lol-challenges-v1">Official API Documentation</a>
+    pub fn challenges_v1(&self) -> ChallengesV1<'_> {
+        ChallengesV1::new(self)
+    }
+
     /// Handle for LeagueV4 endpoints.
     ///
     /// <a href="https://developer.riotgames.com/apis#league-v4">Official API Documentation</a>
-    pub fn league_v4(&self) -> LeagueV4 {
+    pub fn league_v4(&self) -> LeagueV4<'_> {
         LeagueV4::new(self)
     }
 
+    /// Handle for LeagueExpV4 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#league-exp-v4">Official API Documentation</a>
+    pub fn league_exp_v4(&self) -> LeagueExpV4<'_> {
+        LeagueExpV4::new(self)
+    }
+
     /// Handle for ChampionMasteryV4 endpoints.
     ///
     /// <a href="https://developer.riotgames.com/apis#champion-mastery-v4">Official API Documentation</a>
-    pub fn champion_mastery_v4(&self) -> ChampionMasteryV4 {
+    pub fn champion_mastery_v4(&self) -> ChampionMasteryV4<'_> {
         ChampionMasteryV4::new(self)
     }
 
     /// Handle for MatchV4 endpoints.
     ///
     /// <a href="https://developer.riotgames.com/apis#match-v4">Official API Documentation</a>
-    pub fn match_v4(&self) -> MatchV4 {
+    pub fn match_v4(&self) -> MatchV4<'_> {
         MatchV4::new(self)
     }
 
+    /// Handle for MatchV5 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#match-v5">Official API Documentation</a>
+    pub fn match_v5(&self) -> MatchV5<'_> {
+        MatchV5::new(self)
+    }
+
+    /// Handle for SpectatorV4 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#spectator-v4">Official API Documentation</a>
+    pub fn spectator_v4(&self) -> SpectatorV4<'_> {
+        SpectatorV4::new(self)
+    }
+
+    /// Handle for ThirdPartyCodeV4 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#third-party-code-v4">Official API Documentation</a>
+    pub fn third_party_code_v4(&self) -> ThirdPartyCodeV4<'_> {
+        ThirdPartyCodeV4::new(self)
+    }
+
+    /// Handle for TftSummonerV1 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-summoner-v1">Official API Documentation</a>
+    pub fn tft_summoner_v1(&self) -> TftSummonerV1<'_> {
+        TftSummonerV1::new(self)
+    }
+
+    /// Handle for TftLeagueV1 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-league-v1">Official API Documentation</a>
+    pub fn tft_league_v1(&self) -> TftLeagueV1<'_> {
+        TftLeagueV1::new(self)
+    }
+
+    /// Handle for TftMatchV1 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tft-match-v1">Official API Documentation</a>
+    pub fn tft_match_v1(&self) -> TftMatchV1<'_> {
+        TftMatchV1::new(self)
+    }
+
+    /// Handle for LolStatusV4 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#lol-status-v4">Official API Documentation</a>
+    pub fn lol_status_v4(&self) -> LolStatusV4<'_> {
+        LolStatusV4::new(self)
+    }
+
+    /// Handle for ClashV1 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#clash-v1">Official API Documentation</a>
+    pub fn clash_v1(&self) -> ClashV1<'_> {
+        ClashV1::new(self)
+    }
+
+    /// Handle for TournamentV4 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-v4">Official API Documentation</a>
+    pub fn tournament_v4(&self) -> TournamentV4<'_> {
+        TournamentV4::new(self)
+    }
+
+    /// Handle for TournamentStubV4 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#tournament-stub-v4">Official API Documentation</a>
+    pub fn tournament_stub_v4(&self) -> TournamentStubV4<'_> {
+        TournamentStubV4::new(self)
+    }
+
+    /// Handle for AccountV1 endpoints.
+    ///
+    /// <a href="https://developer.riotgames.com/apis#account-v1">Official API Documentation</a>
+    pub fn account_v1(&self) -> AccountV1<'_> {
+        AccountV1::new(self)
+    }
+
+    /// Replaces the API key used by the following requests.
+    pub fn set_api_key(&self, api_key: String) {
+        self.config.api_key.replace(api_key);
+    }
+
+    /// Asks the configured key source for a new API key. Returns `true` when the key changed.
+    pub fn reload_api_key(&self) -> bool {
+        self.config.api_key.reload()
+    }
+
+    /// Builds the request for the given route, replacing the path placeholders with `args`. The
+    /// `body`, when given, is sent as JSON.
     pub fn build_request<T: WithHost>(
         &self,
-        method: Method,
         region: T,
-        path: String,
+        route: &Route,
+        args: &[&str],
         params: String,
+        body: Option<String>,
     ) -> Result<Request<Body>> {
-        let uri = self.forge_uri(region, path, params)?;
-        debug!("{}: {}", method, uri);
+        self.build_request_with_auth(region, route, args, params, body, &AuthMode::ApiKey)
+    }
 
-        let mut req = Request::new(Body::empty());
-        *req.method_mut() = method;
-        *req.uri_mut() = uri;
+    /// Same as `build_request`, authenticating with the given credentials.
+    pub fn build_request_with_auth<T: WithHost>(
+        &self,
+        region: T,
+        route: &Route,
+        args: &[&str],
+        params: String,
+        body: Option<String>,
+        auth: &AuthMode,
+    ) -> Result<Request<Body>> {
+        let mut req = self.prepare_request(region, route, args, params, body)?;
 
-        req.headers_mut()
-            .insert("X-Riot-Token", HeaderValue::from_str(&self.config.api_key)?);
+        match auth {
+            AuthMode::ApiKey => {
+                if route.auth == Auth::Rso {
+                    return Err(format!("{} requires an RSO access token", route.method_id).into());
+                }
+                req.headers_mut().insert(
+                    "X-Riot-Token",
+                    HeaderValue::from_str(&self.config.api_key.current())?,
+                );
+            }
+            AuthMode::Bearer(access_token) => set_bearer_token(&mut req, access_token)?,
+        }
 
         debug!("{:?}", req);
 
         Ok(req)
     }
 
+    /// Builds the request without any credentials.
+    fn prepare_request<T: WithHost>(
+        &self,
+        region: T,
+        route: &Route,
+        args: &[&str],
+        params: String,
+        body: Option<String>,
+    ) -> Result<Request<Body>> {
+        if region.routing() != route.routing {
+            return Err(format!(
+                "{} expects {:?} routing, got {:?}",
+                route.method_id,
+                route.routing,
+                region.routing()
+            )
+            .into());
+        }
+
+        debug_assert!(
+            form_urlencoded::parse(params.as_bytes())
+                .all(|(name, _)| route.accepts_query_param(&name)),
+            "unexpected query parameters for {}: {}",
+            route.method_id,
+            params
+        );
+
+        let path = route.build_path(args).map_err(|err| match err {
+            FetchError::InvalidRequest(message) => message,
+            err => err.to_string(),
+        })?;
+        let uri = self.forge_uri(region, path, params)?;
+        debug!("{}: {}", route.method, uri);
+
+        let mut req = match body {
+            Some(body) => {
+                let mut req = Request::new(Body::from(body));
+                req.headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                req
+            }
+            None => Request::new(Body::empty()),
+        };
+        *req.method_mut() = route.method.clone();
+        *req.uri_mut() = uri;
+
+        Ok(req)
+    }
+
     fn forge_uri<T: WithHost>(
         &self,
         region: T,
         path: String,
         params: String,
     ) -> std::result::Result<Uri, Error> {
-        let uri = format!(
-            "https://{}{}?{}",
-            region.host(&self.config.api_host),
-            path,
-            params
-        )
-        .parse::<Uri>()?;
+        let mut uri = match self.config.base_url {
+            Some(ref base_url) => format!("{}{}", base_url.trim_end_matches('/'), path),
+            None => format!(
+                "{}://{}{}",
+                SCHEME,
+                region.host(&self.config.api_host),
+                path
+            ),
+        };
+        if !params.is_empty() {
+            uri.push('?');
+            uri.push_str(&params);
+        }
 
-        Ok(uri)
+        Ok(uri.parse::<Uri>()?)
     }
 
-    pub fn get_with_params<'a, R, T>(
+    pub fn get_with_params<R, T>(
         &self,
         region: T,
-        path: String,
+        route: &Route,
+        args: &[&str],
         params: String,
     ) -> impl Future<Item = R, Error = Error>
     where
         R: DeserializeOwned + Debug,
         T: WithHost,
     {
-        self.get_data(region, path, params)
+        self.get_data(region, route, args, params, None)
     }
 
-    pub fn get<'a, R, T>(&self, region: T, path: String) -> impl Future<Item = R, Error = Error>
+    pub fn get<R, T>(
+        &self,
+        region: T,
+        route: &Route,
+        args: &[&str],
+    ) -> impl Future<Item = R, Error = Error>
     where
         R: DeserializeOwned + Debug,
         T: WithHost,
     {
-        self.get_data(region, path, String::from(""))
+        self.get_data(region, route, args, String::from(""), None)
     }
 
-    fn get_data<'a, R, T>(
+    /// Same as `get`, but resolves to `None` when the resource does not exist (`404`).
+    pub fn get_optional<R, T>(
         &self,
         region: T,
-        path: String,
-        params: String,
+        route: &Route,
+        args: &[&str],
+    ) -> impl Future<Item = Option<R>, Error = Error>
+    where
+        R: DeserializeOwned + Debug,
+        T: WithHost,
+    {
+        optional(self.get_data(region, route, args, String::from(""), None))
+    }
+
+    /// Same as `get`, authenticating with the given RSO access token instead of the API key.
+    pub fn get_with_token<R, T>(
+        &self,
+        region: T,
+        route: &Route,
+        args: &[&str],
+        access_token: &str,
     ) -> impl Future<Item = R, Error = Error>
     where
         R: DeserializeOwned + Debug,
         T: WithHost,
     {
-        let req = self
-            .build_request(Method::GET, region, path, params)
-            .unwrap();
+        let auth = AuthMode::Bearer(access_token.to_owned());
+        match self.build_request_with_auth(region, route, args, String::new(), None, &auth) {
+            Ok(req) => future::Either::A(send(&self.config.client, None, req)),
+            Err(err) => future::Either::B(future::err(
+                FetchError::InvalidRequest(err.to_string()).into(),
+            )),
+        }
+    }
+
+    /// Same as `get_with_token`, getting the access token from the configured `TokenProvider`.
+    pub fn get_with_rso<R, T>(
+        &self,
+        region: T,
+        route: &Route,
+        args: &[&str],
+    ) -> impl Future<Item = R, Error = Error>
+    where
+        R: DeserializeOwned + Debug,
+        T: WithHost,
+    {
+        let provider = match &self.config.token_provider {
+            Some(provider) => provider.clone(),
+            None => {
+                return future::Either::B(future::err(
+                    FetchError::InvalidRequest(format!(
+                        "{} requires a token provider",
+                        route.method_id
+                    ))
+                    .into(),
+                ))
+            }
+        };
+        let mut req = match self.prepare_request(region, route, args, String::new(), None) {
+            Ok(req) => req,
+            Err(err) => {
+                return future::Either::B(future::err(
+                    FetchError::InvalidRequest(err.to_string()).into(),
+                ))
+            }
+        };
+        let client = self.config.client.clone();
 
-        self.config
-            .client
-            .request(req)
-            .from_err::<FetchError>()
-            .and_then(|res| {
-                if !res.status().is_success() {
-                    future::Either::A(future::err(FetchError::Status(res.status())))
-                } else {
-                    future::Either::B(res.into_body().concat2().from_err())
+        let request = provider.access_token().and_then(move |access_token| {
+            if let Err(err) = set_bearer_token(&mut req, &access_token) {
+                return future::Either::B(future::err(
+                    FetchError::InvalidRequest(err.to_string()).into(),
+                ));
+            }
+
+            future::Either::A(send(&client, None, req).map_err(move |err: Error| {
+                if let Some(FetchError::Status(StatusCode::UNAUTHORIZED)) = err.downcast_ref() {
+                    provider.invalidate(&access_token);
                 }
-            })
-            .from_err::<FetchError>()
-            .and_then(|chunk| {
-                let data = serde_json::from_slice(&chunk)?;
-                debug!("{:?}", data);
+                err
+            }))
+        });
 
-                Ok(data)
-            })
-            .from_err()
+        future::Either::A(request)
+    }
+
+    /// Sends `body` as JSON through a `POST` route.
+    pub fn post<B, R, T>(
+        &self,
+        region: T,
+        route: &Route,
+        args: &[&str],
+        params: String,
+        body: &B,
+    ) -> impl Future<Item = R, Error = Error>
+    where
+        B: Serialize,
+        R: DeserializeOwned + Debug,
+        T: WithHost,
+    {
+        debug_assert_eq!(route.method, Method::POST, "{}", route.method_id);
+        self.send_json(region, route, args, params, body)
+    }
+
+    /// Sends `body` as JSON through a `PUT` route.
+    pub fn put<B, R, T>(
+        &self,
+        region: T,
+        route: &Route,
+        args: &[&str],
+        params: String,
+        body: &B,
+    ) -> impl Future<Item = R, Error = Error>
+    where
+        B: Serialize,
+        R: DeserializeOwned + Debug,
+        T: WithHost,
+    {
+        debug_assert_eq!(route.method, Method::PUT, "{}", route.method_id);
+        self.send_json(region, route, args, params, body)
+    }
+
+    fn send_json<B, R, T>(
+        &self,
+        region: T,
+        route: &Route,
+        args: &[&str],
+        params: String,
+        body: &B,
+    ) -> impl Future<Item = R, Error = Error>
+    where
+        B: Serialize,
+        R: DeserializeOwned + Debug,
+        T: WithHost,
+    {
+        match serde_json::to_string(body) {
+            Ok(body) => future::Either::A(self.get_data(region, route, args, params, Some(body))),
+            Err(err) => future::Either::B(future::err(FetchError::Json(err).into())),
+        }
     }
+
+    fn get_data<R, T>(
+        &self,
+        region: T,
+        route: &Route,
+        args: &[&str],
+        params: String,
+        body: Option<String>,
+    ) -> impl Future<Item = R, Error = Error>
+    where
+        R: DeserializeOwned + Debug,
+        T: WithHost,
+    {
+        match self.build_request(region, route, args, params, body) {
+            Ok(req) => future::Either::A(send(
+                &self.config.client,
+                Some(self.config.api_key.clone()),
+                req,
+            )),
+            Err(err) => future::Either::B(future::err(
+                FetchError::InvalidRequest(err.to_string()).into(),
+            )),
+        }
+    }
+}
+
+/// Credentials used for a single request.
+pub enum AuthMode {
+    /// The API key of the client, sent as `X-Riot-Token`.
+    ApiKey,
+    /// A Riot Sign-On access token, sent as `Authorization: Bearer`.
+    Bearer(String),
+}
+
+fn set_bearer_token(req: &mut Request<Body>, access_token: &str) -> Result<()> {
+    req.headers_mut().insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(&format!("Bearer {}", access_token))?,
+    );
+
+    Ok(())
+}
+
+/// Sends the request and decodes the JSON response. `api_key` is the key the request was
+/// authenticated with, reloaded when Riot rejects it. The call fails with `KeyExpired` only when
+/// a new key was loaded, so that retrying it makes sense.
+fn send<R>(
+    client: &HttpsClient,
+    api_key: Option<ApiKey>,
+    req: Request<Body>,
+) -> impl Future<Item = R, Error = Error>
+where
+    R: DeserializeOwned + Debug,
+{
+    client
+        .request(req)
+        .and_then(|res| {
+            let status = res.status();
+            res.into_body().concat2().map(move |chunk| (status, chunk))
+        })
+        .from_err::<FetchError>()
+        .and_then(move |(status, chunk)| match api_key {
+            _ if status.is_success() => Ok(chunk),
+            Some(ref api_key) if is_rejected_key(status) && api_key.reload() => {
+                info!("API key was rejected, a new key has been loaded");
+                Err(FetchError::KeyExpired(status))
+            }
+            _ => Err(FetchError::Status(status)),
+        })
+        .and_then(|chunk| {
+            // Some endpoints, like PUT ones, answer without a body.
+            let data = if chunk.is_empty() {
+                serde_json::from_slice(b"null")?
+            } else {
+                serde_json::from_slice(&chunk)?
+            };
+            debug!("{:?}", data);
+
+            Ok(data)
+        })
+        .from_err()
+}
+
+/// Resolves to `None` instead of failing when the resource does not exist (`404`).
+pub(crate) fn optional<R, F>(request: F) -> impl Future<Item = Option<R>, Error = Error>
+where
+    F: Future<Item = R, Error = Error>,
+{
+    request
+        .map(Some)
+        .or_else(|err| match err.downcast_ref::<FetchError>() {
+            Some(FetchError::Status(StatusCode::NOT_FOUND)) => Ok(None),
+            _ => Err(err),
+        })
+}
+
+/// Riot answers with `401 Unauthorized` when the key is missing or malformed, and with a plain
+/// `403 Forbidden` once it expires. A `403` can also be a call the key is not allowed to make,
+/// like tournament endpoints with a development key, which the key source can't tell apart.
+fn is_rejected_key(status: StatusCode) -> bool {
+    status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN
 }
 
 pub struct RustApiConfig {
     api_host: String,
-    api_key: String,
+    base_url: Option<String>,
+    api_key: ApiKey,
     client: HttpsClient,
+    token_provider: Option<Arc<dyn TokenProvider>>,
 }
 
 impl RustApiConfig {
@@ -166,25 +568,465 @@ impl RustApiConfig {
             None => String::from("api.riotgames.com"),
         };
 
-        let https = HttpsConnector::new(4).unwrap();
-        let client = Client::builder().build::<_, hyper::Body>(https);
+        let client = Client::builder().build::<_, hyper::Body>(build_connector());
 
         Self {
             api_host,
-            api_key,
+            base_url: None,
+            api_key: ApiKey::new(api_key),
             client,
+            token_provider: None,
         }
     }
+
+    /// Sends every request to `base_url`, like `http://127.0.0.1:8080`, instead of the host of
+    /// the platform or region. Meant for proxies and mock servers.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.to_owned());
+        self
+    }
+
+    /// Source queried for a replacement key whenever Riot rejects the current one.
+    pub fn with_key_source<S: KeySource + 'static>(mut self, source: S) -> Self {
+        self.api_key = self.api_key.with_source(source);
+        self
+    }
+
+    /// Provider of the RSO access tokens used by the endpoints acting on behalf of a player.
+    pub fn with_token_provider<P: TokenProvider + 'static>(mut self, provider: P) -> Self {
+        self.token_provider = Some(Arc::new(provider));
+        self
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 pub type FutureResult<T> = dyn Future<Item = T, Error = Error>;
-pub type HttpsClient = Client<HttpsConnector<HttpConnector>>;
+pub type HttpsClient = Client<Connector>;
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::constants::{Champion, Division, MatchType, Platforms, Queue, Regions, Tier};
+    use crate::endpoints::routes::{
+        ACCOUNT_V1_GET_BY_ACCESS_TOKEN, ACCOUNT_V1_GET_BY_RIOT_ID, CHAMPION_V3_GET_CHAMPION_INFO,
+        LEAGUE_EXP_V4_GET_LEAGUE_ENTRIES, LEAGUE_V4_GET_LEAGUE_ENTRIES,
+        LOL_STATUS_V4_GET_PLATFORM_DATA, MATCH_V4_GET_MATCHLIST, MATCH_V5_GET_MATCH_IDS_BY_PUUID,
+        SUMMONER_V4_GET_BY_ACCESS_TOKEN, SUMMONER_V4_GET_BY_SUMMONER_NAME,
+        TOURNAMENT_STUB_V4_REGISTER_TOURNAMENT,
+    };
+    use crate::endpoints::{
+        to_query, GetLeagueEntriesParams, GetMatchIdsParameters, GetMatchlistParameters,
+    };
+    use crate::models::RiotId;
+    use chrono::{TimeZone, Utc};
+    use hyper::service::service_fn_ok;
+    use hyper::{Response, Server};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use tokio::runtime::{current_thread, Runtime};
+
+    /// Starts a fake server answering every request with the given status and body. Returns its
+    /// URL.
+    fn fake_server(rt: &mut Runtime, status: u16, body: &'static str) -> String {
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(move || {
+            service_fn_ok(move |_| {
+                Response::builder()
+                    .status(status)
+                    .body(Body::from(body))
+                    .unwrap()
+            })
+        });
+        let url = format!("http://{}/", server.local_addr());
+        rt.spawn(server.map_err(|err| panic!("fake server failed: {}", err)));
+
+        url
+    }
+
+    /// Sends a request authenticated with a key whose source counts how many times it's asked
+    /// for a replacement. The source only has a new key when `rotated` is set.
+    fn send_with_key_source(
+        rt: &mut Runtime,
+        url: &str,
+        rotated: bool,
+    ) -> (std::result::Result<serde_json::Value, Error>, usize) {
+        let reloads = Arc::new(AtomicUsize::new(0));
+        let counter = reloads.clone();
+        let api_key = ApiKey::new(String::from("RGAPI-old")).with_source(move || {
+            let reload = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            if rotated {
+                Some(format!("RGAPI-new-{}", reload))
+            } else {
+                Some(String::from("RGAPI-old"))
+            }
+        });
+        let req = Request::get(url).body(Body::empty()).unwrap();
+
+        let result = rt.block_on(send(&api().config.client, Some(api_key), req));
+        (result, reloads.load(Ordering::SeqCst))
+    }
+
+    fn api() -> RiotApi {
+        RiotApi::new(RustApiConfig::new(String::from("RGAPI-test"), None))
+    }
+
+    fn uri<T: WithHost>(region: T, route: &Route, args: &[&str], params: &str) -> String {
+        api()
+            .build_request(region, route, args, params.to_owned(), None)
+            .unwrap()
+            .uri()
+            .to_string()
+    }
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn builds_golden_urls() {
+        assert_eq!(
+            uri(Platforms::Euw1, &CHAMPION_V3_GET_CHAMPION_INFO, &[], ""),
+            format!(
+                "{}://euw1.api.riotgames.com/lol/platform/v3/champion-rotations",
+                SCHEME
+            )
+        );
+        assert_eq!(
+            uri(
+                Platforms::Kr,
+                &SUMMONER_V4_GET_BY_SUMMONER_NAME,
+                &["Hide on bush"],
+                ""
+            ),
+            format!(
+                "{}://kr.api.riotgames.com/lol/summoner/v4/summoners/by-name/Hide%20on%20bush",
+                SCHEME
+            )
+        );
+        assert_eq!(
+            uri(
+                Platforms::Na1,
+                &LEAGUE_V4_GET_LEAGUE_ENTRIES,
+                &["RANKED_SOLO_5x5", "GOLD", "II"],
+                "page=2"
+            ),
+            format!(
+                "{}://na1.api.riotgames.com/lol/league/v4/entries/RANKED_SOLO_5x5/GOLD/II?page=2",
+                SCHEME
+            )
+        );
+    }
+
+    #[test]
+    fn builds_league_exp_urls() {
+        let parameters = GetLeagueEntriesParams::new().page(3);
+
+        assert_eq!(
+            uri(
+                Platforms::Kr,
+                &LEAGUE_EXP_V4_GET_LEAGUE_ENTRIES,
+                &[
+                    Queue::RankedSolo5x5.value(),
+                    Tier::Challenger.value(),
+                    Division::I.value()
+                ],
+                &to_query(&parameters)
+            ),
+            format!(
+                "{}://kr.api.riotgames.com/lol/league-exp/v4/entries/RANKED_SOLO_5x5/CHALLENGER/I?page=3",
+                SCHEME
+            )
+        );
+    }
+
+    #[test]
+    fn builds_status_urls_for_every_platform() {
+        let platforms = vec![
+            (Platforms::Br1, "br1"),
+            (Platforms::Eun1, "eun1"),
+            (Platforms::Euw1, "euw1"),
+            (Platforms::Jp1, "jp1"),
+            (Platforms::Kr, "kr"),
+            (Platforms::La1, "la1"),
+            (Platforms::La2, "la2"),
+            (Platforms::Na1, "na1"),
+            (Platforms::Oc1, "oc1"),
+            (Platforms::Tr1, "tr1"),
+            (Platforms::Ru, "ru"),
+        ];
+
+        for (platform, name) in platforms {
+            assert_eq!(
+                uri(platform, &LOL_STATUS_V4_GET_PLATFORM_DATA, &[], ""),
+                format!(
+                    "{}://{}.api.riotgames.com/lol/status/v4/platform-data",
+                    SCHEME, name
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn builds_riot_id_urls() {
+        let riot_id = "Hide on bush#KR1".parse::<RiotId>().unwrap();
+
+        assert_eq!(
+            uri(
+                Regions::Asia,
+                &ACCOUNT_V1_GET_BY_RIOT_ID,
+                &[riot_id.game_name(), riot_id.tag_line()],
+                ""
+            ),
+            format!(
+                "{}://asia.api.riotgames.com/riot/account/v1/accounts/by-riot-id/Hide%20on%20bush/KR1",
+                SCHEME
+            )
+        );
+    }
+
+    #[test]
+    fn builds_match_v5_urls() {
+        let parameters = GetMatchIdsParameters::new()
+            .start_time(Utc.timestamp_opt(1_625_000_000, 0).unwrap())
+            .queue(420)
+            .match_type(MatchType::Ranked)
+            .count(100);
+
+        assert_eq!(
+            uri(
+                Regions::Europe,
+                &MATCH_V5_GET_MATCH_IDS_BY_PUUID,
+                &["some-puuid"],
+                &to_query(&parameters)
+            ),
+            format!(
+                "{}://europe.api.riotgames.com/lol/match/v5/matches/by-puuid/some-puuid/ids?count=100&queue=420&startTime=1625000000&type=ranked",
+                SCHEME
+            )
+        );
+        assert_eq!(to_query(&GetMatchIdsParameters::new()), "");
+    }
+
+    #[test]
+    fn builds_matchlist_urls() {
+        let parameters = GetMatchlistParameters::new()
+            .champion(Champion(1))
+            .champion(Champion(103))
+            .queue(420)
+            .queue(440)
+            .begin_time(Utc.timestamp_opt(1_625_000_000, 0).unwrap());
+
+        assert_eq!(
+            uri(
+                Platforms::Euw1,
+                &MATCH_V4_GET_MATCHLIST,
+                &["account-id"],
+                &to_query(&parameters)
+            ),
+            format!(
+                "{}://euw1.api.riotgames.com/lol/match/v4/matchlists/by-account/account-id?beginTime=1625000000000&champion=1&champion=103&queue=420&queue=440",
+                SCHEME
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_routing() {
+        let request = api().build_request(
+            Regions::Europe,
+            &CHAMPION_V3_GET_CHAMPION_INFO,
+            &[],
+            String::new(),
+            None,
+        );
+
+        assert!(request.is_err());
+    }
+
+    #[test]
+    fn sends_api_key() {
+        let api = api();
+        api.set_api_key(String::from("RGAPI-replaced"));
+
+        let request = api
+            .build_request(
+                Platforms::Euw1,
+                &CHAMPION_V3_GET_CHAMPION_INFO,
+                &[],
+                String::new(),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(request.headers()["X-Riot-Token"], "RGAPI-replaced");
+    }
+
+    #[test]
+    fn sends_bearer_token() {
+        let request = api()
+            .build_request_with_auth(
+                Regions::Europe,
+                &ACCOUNT_V1_GET_BY_ACCESS_TOKEN,
+                &[],
+                String::new(),
+                None,
+                &AuthMode::Bearer(String::from("access-token")),
+            )
+            .unwrap();
+
+        assert_eq!(request.headers()[AUTHORIZATION], "Bearer access-token");
+        assert!(request.headers().get("X-Riot-Token").is_none());
+    }
+
+    #[test]
+    fn rejects_api_key_for_rso_routes() {
+        let request = api().build_request(
+            Platforms::Euw1,
+            &SUMMONER_V4_GET_BY_ACCESS_TOKEN,
+            &[],
+            String::new(),
+            None,
+        );
+
+        assert!(request.is_err());
+    }
+
+    #[test]
+    fn requires_token_provider_for_rso() {
+        let err = api()
+            .get_with_rso::<serde_json::Value, _>(
+                Platforms::Euw1,
+                &SUMMONER_V4_GET_BY_ACCESS_TOKEN,
+                &[],
+            )
+            .wait()
+            .unwrap_err();
+
+        match err.downcast_ref::<FetchError>() {
+            Some(FetchError::InvalidRequest(message)) => {
+                assert!(message.contains("summoner-v4.getByAccessToken"))
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn sends_json_body() {
+        let request = api()
+            .build_request(
+                Regions::Americas,
+                &TOURNAMENT_STUB_V4_REGISTER_TOURNAMENT,
+                &[],
+                String::new(),
+                Some(String::from(r#"{"providerId":1}"#)),
+            )
+            .unwrap();
+
+        assert_eq!(request.method(), Method::POST);
+        assert_eq!(request.headers()[CONTENT_TYPE], "application/json");
+    }
+
+    #[test]
+    fn reloads_rejected_keys() {
+        let mut rt = Runtime::new().unwrap();
+        let rejected = vec![
+            fake_server(&mut rt, 401, r#"{"status":{"message":"Unauthorized"}}"#),
+            fake_server(
+                &mut rt,
+                403,
+                r#"{"status":{"message":"Forbidden","status_code":403}}"#,
+            ),
+        ];
+
+        for url in rejected {
+            let (result, reloads) = send_with_key_source(&mut rt, &url, true);
+
+            match result.unwrap_err().downcast_ref::<FetchError>() {
+                Some(FetchError::KeyExpired(_)) => assert_eq!(reloads, 1),
+                other => panic!("unexpected error: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_status_when_key_is_unchanged() {
+        let mut rt = Runtime::new().unwrap();
+        let url = fake_server(&mut rt, 403, r#"{"status":{"message":"Forbidden"}}"#);
+
+        let (result, reloads) = send_with_key_source(&mut rt, &url, false);
+
+        match result.unwrap_err().downcast_ref::<FetchError>() {
+            Some(FetchError::Status(status)) => assert_eq!(status.as_u16(), 403),
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(reloads, 1);
+    }
+
+    #[test]
+    fn resolves_missing_resources_to_none() {
+        let mut rt = Runtime::new().unwrap();
+        let send_to = |rt: &mut Runtime, url: String| {
+            let req = Request::get(url).body(Body::empty()).unwrap();
+            rt.block_on(optional(send::<serde_json::Value>(
+                &api().config.client,
+                None,
+                req,
+            )))
+        };
+
+        let found = fake_server(&mut rt, 200, r#"{"id":"a"}"#);
+        let missing = fake_server(&mut rt, 404, r#"{"status":{"message":"Data not found"}}"#);
+        let failing = fake_server(&mut rt, 503, "");
+
+        assert_eq!(
+            send_to(&mut rt, found).unwrap(),
+            Some(serde_json::json!({"id": "a"}))
+        );
+        assert_eq!(send_to(&mut rt, missing).unwrap(), None);
+        match send_to(&mut rt, failing)
+            .unwrap_err()
+            .downcast_ref::<FetchError>()
+        {
+            Some(FetchError::Status(status)) => assert_eq!(status.as_u16(), 503),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+    /// Hands out the same access token, recording the ones it's told were rejected.
+    struct FixedToken(Arc<Mutex<Vec<String>>>);
+
+    impl TokenProvider for FixedToken {
+        fn access_token(&self) -> Box<dyn Future<Item = String, Error = Error> + Send> {
+            Box::new(future::ok(String::from("token-1")))
+        }
+
+        fn invalidate(&self, access_token: &str) {
+            self.0.lock().unwrap().push(access_token.to_owned());
+        }
+    }
+
+    #[test]
+    fn invalidates_rejected_access_tokens() {
+        let mut rt = Runtime::new().unwrap();
+
+        for &(status, invalidations) in &[(401, 1), (403, 0)] {
+            let invalidated = Arc::new(Mutex::new(Vec::new()));
+            let url = fake_server(&mut rt, status, "");
+            let api = RiotApi::new(
+                RustApiConfig::new(String::from("RGAPI-test"), None)
+                    .with_base_url(&url)
+                    .with_token_provider(FixedToken(invalidated.clone())),
+            );
+
+            let result = current_thread::Runtime::new().unwrap().block_on(
+                api.get_with_rso::<serde_json::Value, _>(
+                    Platforms::Euw1,
+                    &SUMMONER_V4_GET_BY_ACCESS_TOKEN,
+                    &[],
+                ),
+            );
+
+            assert!(result.is_err());
+            assert_eq!(invalidated.lock().unwrap().len(), invalidations);
+        }
+    }
 }
@@ -0,0 +1,287 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use failure::Error;
+use futures::future::{self, Shared};
+use hyper::header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use hyper::rt::{Future, Stream};
+use hyper::{Body, Client, Method, Request};
+use log::{debug, info};
+use serde_derive::Deserialize;
+use url::form_urlencoded::Serializer;
+
+use crate::connector::{build_connector, Connector};
+use crate::FetchError;
+
+/// Riot Sign-On token endpoint.
+pub const RSO_TOKEN_URL: &str = "https://auth.riotgames.com/token";
+
+/// Access tokens are refreshed this long before they expire, so they don't expire mid request.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// Provides Riot Sign-On access tokens for the endpoints acting on behalf of a player, like
+/// `SummonerV4::get_me`.
+pub trait TokenProvider: Send + Sync {
+    /// Resolves to a valid access token.
+    fn access_token(&self) -> Box<dyn Future<Item = String, Error = Error> + Send>;
+
+    /// Called when Riot rejects a token from this provider with `401`, so it isn't handed out
+    /// again. Does nothing by default.
+    fn invalidate(&self, _access_token: &str) {}
+}
+
+type RefreshFuture = Box<dyn Future<Item = String, Error = FetchError> + Send>;
+
+/// Gets access tokens from the RSO token endpoint with a refresh token, reusing them until they
+/// are about to expire or get rejected. Concurrent calls share a single refresh, since Riot may
+/// rotate the refresh token and the old one stops working once used.
+pub struct RsoTokenProvider {
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    state: Arc<Mutex<TokenState>>,
+    client: Client<Connector>,
+}
+
+struct TokenState {
+    refresh_token: String,
+    access_token: Option<(String, Instant)>,
+    /// Refresh in flight, awaited by every caller until it completes.
+    refreshing: Option<Shared<RefreshFuture>>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    /// Lifetime of the access token, in seconds.
+    expires_in: u64,
+    /// Riot may rotate the refresh token on every refresh.
+    refresh_token: Option<String>,
+}
+
+impl RsoTokenProvider {
+    pub fn new(client_id: &str, client_secret: &str, refresh_token: &str) -> Self {
+        Self {
+            token_url: String::from(RSO_TOKEN_URL),
+            client_id: client_id.to_owned(),
+            client_secret: client_secret.to_owned(),
+            state: Arc::new(Mutex::new(TokenState {
+                refresh_token: refresh_token.to_owned(),
+                access_token: None,
+                refreshing: None,
+            })),
+            client: Client::builder().build::<_, Body>(build_connector()),
+        }
+    }
+
+    /// Token endpoint used instead of `RSO_TOKEN_URL`.
+    pub fn with_token_url(mut self, token_url: &str) -> Self {
+        self.token_url = token_url.to_owned();
+        self
+    }
+
+    fn build_refresh_request(&self, refresh_token: &str) -> Result<Request<Body>, Error> {
+        let body = Serializer::new(String::new())
+            .append_pair("grant_type", "refresh_token")
+            .append_pair("refresh_token", refresh_token)
+            .finish();
+        let credentials = base64::encode(&format!("{}:{}", self.client_id, self.client_secret));
+
+        let mut req = Request::new(Body::from(body));
+        *req.method_mut() = Method::POST;
+        *req.uri_mut() = self.token_url.parse()?;
+        req.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+        req.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Basic {}", credentials))?,
+        );
+
+        Ok(req)
+    }
+}
+
+impl TokenProvider for RsoTokenProvider {
+    fn access_token(&self) -> Box<dyn Future<Item = String, Error = Error> + Send> {
+        let mut state = self.state.lock().unwrap();
+        if let Some((token, expires_at)) = &state.access_token {
+            if Instant::now() + EXPIRY_MARGIN < *expires_at {
+                return Box::new(future::ok(token.clone()));
+            }
+        }
+        if let Some(refreshing) = &state.refreshing {
+            return Box::new(shared_token(refreshing.clone()));
+        }
+
+        let req = match self.build_refresh_request(&state.refresh_token) {
+            Ok(req) => req,
+            Err(err) => {
+                return Box::new(future::err(
+                    FetchError::InvalidRequest(err.to_string()).into(),
+                ))
+            }
+        };
+        debug!("Refreshing RSO access token");
+
+        let shared_state = self.state.clone();
+        let refresh: RefreshFuture = Box::new(
+            self.client
+                .request(req)
+                .from_err::<FetchError>()
+                .and_then(|res| {
+                    if res.status().is_success() {
+                        future::Either::A(res.into_body().concat2().from_err())
+                    } else {
+                        future::Either::B(future::err(FetchError::Status(res.status())))
+                    }
+                })
+                .then(move |chunk| {
+                    let mut state = shared_state.lock().unwrap();
+                    state.refreshing = None;
+
+                    let response: TokenResponse = serde_json::from_slice(&chunk?)?;
+                    let expires_at = Instant::now() + Duration::from_secs(response.expires_in);
+                    if let Some(refresh_token) = response.refresh_token {
+                        state.refresh_token = refresh_token;
+                    }
+                    state.access_token = Some((response.access_token.clone(), expires_at));
+
+                    Ok(response.access_token)
+                }),
+        );
+        let refresh = refresh.shared();
+        state.refreshing = Some(refresh.clone());
+
+        Box::new(shared_token(refresh))
+    }
+
+    fn invalidate(&self, access_token: &str) {
+        let mut state = self.state.lock().unwrap();
+        if let Some((token, _)) = &state.access_token {
+            if token == access_token {
+                info!("RSO access token was rejected, it will be refreshed");
+                state.access_token = None;
+            }
+        }
+    }
+}
+
+/// Waits for a shared refresh. Errors can't be cloned, so every caller gets its own copy, with
+/// transport and decoding errors turned into their message.
+fn shared_token(refresh: Shared<RefreshFuture>) -> impl Future<Item = String, Error = Error> {
+    refresh
+        .map(|token| (*token).clone())
+        .map_err(|err| match *err {
+            FetchError::Status(status) => FetchError::Status(status).into(),
+            FetchError::KeyExpired(status) => FetchError::KeyExpired(status).into(),
+            FetchError::InvalidRequest(ref message) => {
+                FetchError::InvalidRequest(message.clone()).into()
+            }
+            ref err => FetchError::InvalidRequest(err.to_string()).into(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::service::service_fn;
+    use hyper::{Response, Server};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::runtime::Runtime;
+
+    /// Starts a fake OAuth server answering every request with a new access token. Returns its
+    /// token URL and the number of requests it received.
+    fn fake_token_server(rt: &mut Runtime) -> (String, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(move || {
+            let counter = counter.clone();
+            service_fn(move |req: Request<Body>| {
+                let call = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                let authorized = req.headers()[AUTHORIZATION]
+                    == format!("Basic {}", base64::encode("client-id:client-secret")).as_str();
+
+                req.into_body().concat2().map(move |body| {
+                    let body = String::from_utf8_lossy(&body).into_owned();
+                    if !authorized || !body.contains("grant_type=refresh_token") {
+                        return Response::builder()
+                            .status(400)
+                            .body(Body::empty())
+                            .unwrap();
+                    }
+
+                    Response::new(Body::from(format!(
+                        r#"{{"access_token":"token-{}","expires_in":3600,"refresh_token":"refresh-{}"}}"#,
+                        call, call
+                    )))
+                })
+            })
+        });
+        let url = format!("http://{}/token", server.local_addr());
+        rt.spawn(server.map_err(|err| panic!("fake token server failed: {}", err)));
+
+        (url, calls)
+    }
+
+    #[test]
+    fn refreshes_and_reuses_access_tokens() {
+        let mut rt = Runtime::new().unwrap();
+        let (url, calls) = fake_token_server(&mut rt);
+        let provider =
+            RsoTokenProvider::new("client-id", "client-secret", "refresh-0").with_token_url(&url);
+
+        assert_eq!(rt.block_on(provider.access_token()).unwrap(), "token-1");
+        assert_eq!(rt.block_on(provider.access_token()).unwrap(), "token-1");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(provider.state.lock().unwrap().refresh_token, "refresh-1");
+    }
+
+    #[test]
+    fn shares_concurrent_refreshes() {
+        let mut rt = Runtime::new().unwrap();
+        let (url, calls) = fake_token_server(&mut rt);
+        let provider =
+            RsoTokenProvider::new("client-id", "client-secret", "refresh-0").with_token_url(&url);
+
+        let tokens = rt
+            .block_on(provider.access_token().join(provider.access_token()))
+            .unwrap();
+
+        assert_eq!(tokens, (String::from("token-1"), String::from("token-1")));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(provider.state.lock().unwrap().refreshing.is_none());
+    }
+
+    #[test]
+    fn refreshes_invalidated_tokens() {
+        let mut rt = Runtime::new().unwrap();
+        let (url, calls) = fake_token_server(&mut rt);
+        let provider =
+            RsoTokenProvider::new("client-id", "client-secret", "refresh-0").with_token_url(&url);
+
+        assert_eq!(rt.block_on(provider.access_token()).unwrap(), "token-1");
+        provider.invalidate("token-0");
+        assert_eq!(rt.block_on(provider.access_token()).unwrap(), "token-1");
+        provider.invalidate("token-1");
+        assert_eq!(rt.block_on(provider.access_token()).unwrap(), "token-2");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn reports_rejected_refreshes() {
+        let mut rt = Runtime::new().unwrap();
+        let (url, _) = fake_token_server(&mut rt);
+        let provider =
+            RsoTokenProvider::new("client-id", "wrong-secret", "refresh-0").with_token_url(&url);
+
+        let err = rt.block_on(provider.access_token()).unwrap_err();
+
+        match err.downcast_ref::<FetchError>() {
+            Some(FetchError::Status(status)) => assert_eq!(status.as_u16(), 400),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Decodes recorded API responses, one per endpoint, with the DTOs they're returned as.
+
+use std::collections::HashMap;
+use std::fs;
+
+use lol_api::constants::{ChallengeLevel, Champion, Lane, Position, Queue, Tier};
+use lol_api::models::{
+    match_v5, tft, AccountDTO, ActiveShardDTO, ApexPlayerInfoDTO, ChallengeConfigInfoDTO,
+    ChampionInfo, ChampionMasteryDTO, ClashPlayerDTO, ClashTeamDTO, ClashTier, ClashTournamentDTO,
+    CurrentGameInfo, FeaturedGames, LeagueEntryDTO, LeagueListDTO, LobbyEventDTOWrapper, MatchDTO,
+    MatchEvent, MatchTimelineDTO, MatchlistDTO, PlatformDataDTO, PlayerInfoDTO, SummonerDTO,
+    TournamentCodeDTO,
+};
+use serde::de::DeserializeOwned;
+
+/// Decodes `tests/fixtures/<name>.json`.
+fn decode<T: DeserializeOwned>(name: &str) -> T {
+    let path = format!(
+        "{}/tests/fixtures/{}.json",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    let json = fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {}", path, err));
+
+    serde_json::from_str(&json).unwrap_or_else(|err| panic!("{}: {}", name, err))
+}
+
+#[test]
+fn decodes_summoner_v4() {
+    let summoner: SummonerDTO = decode("summoner-v4.getBySummonerName");
+
+    assert_eq!(summoner.name, "Hide on bush");
+    assert_eq!(summoner.revision_date.timestamp(), 1_625_012_345);
+}
+
+#[test]
+fn decodes_champion_v3() {
+    let rotation: ChampionInfo = decode("champion-v3.getChampionInfo");
+
+    assert_eq!(rotation.free_champion_ids_for_new_players.len(), 10);
+    assert_eq!(rotation.max_new_player_level, 10);
+}
+
+#[test]
+fn decodes_champion_mastery_v4() {
+    let masteries: Vec<ChampionMasteryDTO> = decode("champion-mastery-v4.getAllChampionMasteries");
+
+    assert_eq!(masteries[0].champion_id, Champion::LEBLANC);
+    assert_eq!(masteries[0].champion_points, 1_372_213);
+    assert!(masteries[0].summoner_id.is_some());
+}
+
+#[test]
+fn decodes_league_v4() {
+    let league: LeagueListDTO = decode("league-v4.getChallengerLeague");
+    let entries: Vec<LeagueEntryDTO> = decode("league-v4.getLeagueEntriesForSummoner");
+
+    assert_eq!(league.entries.len(), 2);
+    assert_eq!(league.entries[0].league_points, 1187);
+    assert_eq!(entries[1].tier, Tier::Challenger);
+    assert!(entries[1].rank() > entries[0].rank());
+}
+
+#[test]
+fn decodes_match_v4() {
+    let reforged: MatchDTO = decode("match-v4.getMatch");
+    let legacy: MatchDTO = decode("match-v4.getMatch.legacy");
+    let matchlist: MatchlistDTO = decode("match-v4.getMatchlist");
+    let ids: Vec<u64> = decode("match-v4.getMatchIdsByTournamentCode");
+
+    let participant = &reforged.participants[0];
+    assert_eq!(reforged.game_id, 5_262_518_943);
+    assert!(participant.runes.is_none());
+    assert_eq!(participant.stats.longest_time_spent_living, 731);
+    assert_eq!(participant.stats.perk5_var1, Some(9));
+    assert_eq!(participant.stats.time_c_c_ing_others, 31);
+    assert!(reforged.participants[1]
+        .highest_achieved_season_tier
+        .is_none());
+    assert_eq!(reforged.teams[0].dominion_victory_score, Some(0));
+    assert!(reforged.teams.iter().any(|team| team
+        .bans
+        .iter()
+        .any(|ban| ban.champion_id == Champion::NONE)));
+
+    let participant = &legacy.participants[0];
+    assert_eq!(participant.runes.as_ref().map(Vec::len), Some(4));
+    assert_eq!(participant.masteries.as_ref().unwrap()[0].rank, 5);
+    assert!(participant.highest_achieved_season_tier.is_none());
+    assert!(participant.stats.perk0.is_none());
+    assert!(legacy.participant_identities[0]
+        .player
+        .summoner_id
+        .is_none());
+
+    assert_eq!(matchlist.matches[2].game_id, 5_262_401_187);
+    assert_eq!(matchlist.matches[2].lane, Lane::Jungle);
+    assert_eq!(matchlist.matches[0].position(), Some(Position::Mid));
+    assert_eq!(ids.len(), 2);
+}
+
+#[test]
+fn decodes_match_v4_timeline() {
+    let timeline: MatchTimelineDTO = decode("match-v4.getMatchTimeline");
+
+    let last_frame = timeline.frames.last().unwrap();
+    assert!(last_frame.participant_frames[&1].position.is_none());
+    assert!(timeline.frames[2]
+        .events
+        .iter()
+        .all(|event| !matches!(event, MatchEvent::Unknown)));
+    match &last_frame.events[1] {
+        MatchEvent::BuildingKill { tower_type, .. } => assert!(tower_type.is_none()),
+        event => panic!("unexpected event: {:?}", event),
+    }
+}
+
+#[test]
+fn decodes_match_v5() {
+    let game: match_v5::MatchDTO = decode("match-v5.getMatch");
+    let timeline: match_v5::TimelineDTO = decode("match-v5.getTimeline");
+    let ids: Vec<String> = decode("match-v5.getMatchIdsByPUUID");
+
+    assert_eq!(game.info.participants[1].champion_name, "Ahri");
+    assert!(game.info.game_end_timestamp.unwrap() > game.info.game_start_timestamp);
+    match &timeline.info.frames[1].events[2] {
+        match_v5::MatchEvent::ChampionKill {
+            victim_id,
+            victim_damage_dealt,
+            ..
+        } => {
+            assert_eq!(*victim_id, 6);
+            assert_eq!(victim_damage_dealt[0].spell_name, "ahriorbofdeception");
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+    assert!(timeline
+        .info
+        .frames
+        .iter()
+        .flat_map(|frame| &frame.events)
+        .all(|event| !matches!(event, match_v5::MatchEvent::Unknown)));
+    assert_eq!(ids[0], game.metadata.match_id);
+}
+
+#[test]
+fn decodes_spectator_v4() {
+    let game: CurrentGameInfo = decode("spectator-v4.getCurrentGameInfoBySummoner");
+    let featured: FeaturedGames = decode("spectator-v4.getFeaturedGames");
+
+    assert_eq!(game.participants[0].perks.perk_ids.len(), 9);
+    assert_eq!(featured.game_list[0].game_queue_config_id, 450);
+}
+
+#[test]
+fn decodes_lol_status_v4() {
+    let status: PlatformDataDTO = decode("lol-status-v4.getPlatformData");
+
+    assert_eq!(
+        status.maintenances[0].title("en_GB"),
+        Some("Scheduled maintenance")
+    );
+    assert!(status.incidents[0].updates.is_empty());
+}
+
+#[test]
+fn decodes_clash_v1() {
+    let players: Vec<ClashPlayerDTO> = decode("clash-v1.getPlayersBySummoner");
+    let team: ClashTeamDTO = decode("clash-v1.getTeamById");
+    let tournaments: Vec<ClashTournamentDTO> = decode("clash-v1.getTournaments");
+
+    assert!(players[1].team_id.is_none());
+    assert_eq!(team.players.len(), 3);
+    assert_eq!(team.tier, ClashTier(1));
+    assert!(tournaments[1].schedule[0].cancelled);
+}
+
+#[test]
+fn decodes_tournament_v4() {
+    let code: TournamentCodeDTO = decode("tournament-v4.getTournamentCode");
+    let events: LobbyEventDTOWrapper = decode("tournament-v4.getLobbyEventsByCode");
+    let codes: Vec<String> = decode("tournament-v4.createTournamentCode");
+
+    assert_eq!(code.participants.len(), 2);
+    assert!(events.event_list[0].summoner_id.is_none());
+    assert!(events.event_list[1].summoner_id.is_some());
+    assert_eq!(codes.len(), 2);
+}
+
+#[test]
+fn decodes_account_v1() {
+    let account: AccountDTO = decode("account-v1.getByRiotId");
+    let shard: ActiveShardDTO = decode("account-v1.getActiveShard");
+
+    assert_eq!(account.riot_id().unwrap().to_string(), "Hide on bush#KR1");
+    assert_eq!(shard.active_shard, "ap");
+}
+
+#[test]
+fn decodes_third_party_code_v4() {
+    let code: String = decode("third-party-code-v4.getThirdPartyCodeBySummonerId");
+
+    assert_eq!(code, "verification-1a2b3c");
+}
+
+#[test]
+fn decodes_challenges_v1() {
+    let configs: Vec<ChallengeConfigInfoDTO> = decode("challenges-v1.getAllChallengeConfigs");
+    let percentiles: HashMap<i64, HashMap<ChallengeLevel, f64>> =
+        decode("challenges-v1.getAllChallengePercentiles");
+    let leaderboard: Vec<ApexPlayerInfoDTO> = decode("challenges-v1.getChallengeLeaderboards");
+    let player: PlayerInfoDTO = decode("challenges-v1.getPlayerData");
+
+    assert!(configs[0].tracking.is_none());
+    assert_eq!(configs[2].end_timestamp, Some(1_668_495_600_000));
+    assert_eq!(percentiles[&0][&ChallengeLevel::Master], 0.001);
+    assert_eq!(leaderboard[0].position, 1);
+    assert_eq!(player.challenges[1].players_in_level, Some(311));
+}
+
+#[test]
+fn decodes_tft() {
+    let entries: Vec<tft::LeagueEntryDTO> = decode("tft-league-v1.getLeagueEntriesForSummoner");
+    let game: tft::MatchDTO = decode("tft-match-v1.getMatch");
+    let ids: Vec<String> = decode("tft-match-v1.getMatchIdsByPUUID");
+
+    assert_eq!(entries[0].queue_type, Queue::RankedTft);
+    assert_eq!(entries[1].queue_type, Queue::RankedTftTurbo);
+    assert!(entries[1].tier.is_none());
+    assert!(entries[1].rank().is_none());
+    assert_eq!(entries[1].rated_rating, Some(2311));
+    assert_eq!(
+        game.info.participants[0].units[0].chosen.as_deref(),
+        Some("Set5_Forgotten")
+    );
+    assert_eq!(ids[0], game.metadata.match_id);
+}
@@ -1,17 +1,19 @@
-use handlebars::Handlebars;
+use handlebars::{handlebars_helper, Handlebars};
 use std::fs::File;
 use std::io::prelude::*;
-use std::io::Result;
 use std::error::Error;
 use std::path::Path;
 
 use super::endpoints::ToDescriptor;
 
+handlebars_helper!(lowercase: |value: str| value.to_lowercase());
+
 pub fn parse_file_template<T>(path: &'static str, data: &T) -> String
 where
     T: ToDescriptor,
 {
     let mut reg = Handlebars::new();
+    reg.register_helper("lowercase", Box::new(lowercase));
     reg.register_template_file("file", path).unwrap();
 
     reg.render("file", &data.descriptor()).unwrap()